@@ -0,0 +1,188 @@
+/*
+  Copyright (c) 2018-present evan GmbH.
+
+  Licensed under the Apache License, Version 2.0 (the "License");
+  you may not use this file except in compliance with the License.
+  You may obtain a copy of the License at
+
+      http://www.apache.org/licenses/LICENSE-2.0
+
+  Unless required by applicable law or agreed to in writing, software
+  distributed under the License is distributed on an "AS IS" BASIS,
+  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+  See the License for the specific language governing permissions and
+  limitations under the License.
+*/
+
+use chrono::Utc;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+};
+
+pub const DEFAULT_ISSUER_KEY_CACHE_SIZE: usize = 100;
+// keys are re-resolved after this time, so key rotations in DID documents are picked up
+const ISSUER_KEY_CACHE_TTL_SECONDS: i64 = 300;
+
+/// issuer DID, verification method id and proof purpose the key has been checked for
+type CacheKey = (String, String, Option<String>);
+
+struct CacheEntry {
+    public_key: String,
+    expires_at: i64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<CacheKey, CacheEntry>,
+    // least recently used key first
+    usage_order: VecDeque<CacheKey>,
+}
+
+impl CacheState {
+    fn mark_as_used(&mut self, key: &CacheKey) {
+        self.usage_order.retain(|entry| entry != key);
+        self.usage_order.push_back(key.clone());
+    }
+
+    fn remove(&mut self, key: &CacheKey) {
+        self.entries.remove(key);
+        self.usage_order.retain(|entry| entry != key);
+    }
+
+    fn evict_to(&mut self, capacity: usize) {
+        while self.entries.len() > capacity {
+            match self.usage_order.pop_front() {
+                Some(key) => {
+                    self.entries.remove(&key);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+/// Bounded least recently used cache for public keys extracted from issuer DID documents.
+/// Entries expire after a fixed time to pick up changes of DID documents.
+pub(crate) struct IssuerKeyCache {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+impl IssuerKeyCache {
+    /// Creates a new cache, a `capacity` of 0 disables caching.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Updates the number of keys kept in cache, evicting least recently used keys if needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        if let Ok(mut state) = self.state.lock() {
+            state.evict_to(capacity);
+        }
+    }
+
+    pub fn get(
+        &self,
+        issuer_did: &str,
+        verification_method_id: &str,
+        proof_purpose: Option<&str>,
+    ) -> Option<String> {
+        let key = get_cache_key(issuer_did, verification_method_id, proof_purpose);
+        let mut state = self.state.lock().ok()?;
+        let entry = state.entries.get(&key)?;
+        let is_expired = entry.expires_at <= Utc::now().timestamp();
+        let public_key = entry.public_key.to_owned();
+        if is_expired {
+            state.remove(&key);
+            return None;
+        }
+        state.mark_as_used(&key);
+
+        Some(public_key)
+    }
+
+    pub fn insert(
+        &self,
+        issuer_did: &str,
+        verification_method_id: &str,
+        proof_purpose: Option<&str>,
+        public_key: &str,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        let key = get_cache_key(issuer_did, verification_method_id, proof_purpose);
+        if let Ok(mut state) = self.state.lock() {
+            state.entries.insert(
+                key.clone(),
+                CacheEntry {
+                    public_key: public_key.to_owned(),
+                    expires_at: Utc::now().timestamp() + ISSUER_KEY_CACHE_TTL_SECONDS,
+                },
+            );
+            state.mark_as_used(&key);
+            state.evict_to(self.capacity);
+        }
+    }
+}
+
+fn get_cache_key(
+    issuer_did: &str,
+    verification_method_id: &str,
+    proof_purpose: Option<&str>,
+) -> CacheKey {
+    (
+        issuer_did.to_owned(),
+        verification_method_id.to_owned(),
+        proof_purpose.map(|purpose| purpose.to_owned()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IssuerKeyCache;
+
+    const ISSUER_DID: &str = "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA";
+
+    #[test]
+    fn returns_cached_key_for_same_lookup_only() {
+        let cache = IssuerKeyCache::new(10);
+        cache.insert(ISSUER_DID, "#bbs-key-1", Some("assertionMethod"), "key");
+
+        assert_eq!(
+            cache.get(ISSUER_DID, "#bbs-key-1", Some("assertionMethod")),
+            Some("key".to_string())
+        );
+        assert_eq!(cache.get(ISSUER_DID, "#bbs-key-1", None), None);
+        assert_eq!(cache.get(ISSUER_DID, "#bbs-key-2", Some("assertionMethod")), None);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_key() {
+        let cache = IssuerKeyCache::new(2);
+        cache.insert(ISSUER_DID, "#key-1", None, "key 1");
+        cache.insert(ISSUER_DID, "#key-2", None, "key 2");
+        cache.get(ISSUER_DID, "#key-1", None);
+        cache.insert(ISSUER_DID, "#key-3", None, "key 3");
+
+        assert_eq!(cache.get(ISSUER_DID, "#key-1", None), Some("key 1".to_string()));
+        assert_eq!(cache.get(ISSUER_DID, "#key-2", None), None);
+        assert_eq!(cache.get(ISSUER_DID, "#key-3", None), Some("key 3".to_string()));
+    }
+
+    #[test]
+    fn does_not_cache_keys_with_capacity_zero() {
+        let mut cache = IssuerKeyCache::new(1);
+        cache.insert(ISSUER_DID, "#key-1", None, "key 1");
+        cache.set_capacity(0);
+        cache.insert(ISSUER_DID, "#key-2", None, "key 2");
+
+        assert_eq!(cache.get(ISSUER_DID, "#key-1", None), None);
+        assert_eq!(cache.get(ISSUER_DID, "#key-2", None), None);
+    }
+}
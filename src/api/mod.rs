@@ -1,6 +1,27 @@
+#[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+mod issuer_key_cache;
 mod vade_bundle;
 mod vade_evan_api;
 mod vade_evan_error;
+#[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+mod verification_cache;
 
-pub use vade_evan_api::{VadeEvan, VadeEvanConfig, DEFAULT_SIGNER, DEFAULT_TARGET};
+pub use vade_evan_api::{
+    BatchOperation,
+    VadeEvan,
+    VadeEvanConfig,
+    DEFAULT_SIGNER,
+    DEFAULT_TARGET,
+};
 pub use vade_evan_error::VadeEvanError;
+
+pub(crate) use vade_evan_api::parse_did_document;
+
+#[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+pub(crate) use issuer_key_cache::{IssuerKeyCache, DEFAULT_ISSUER_KEY_CACHE_SIZE};
+#[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+pub(crate) use verification_cache::{
+    get_verification_cache_key,
+    VerificationCache,
+    VerificationFlags,
+};
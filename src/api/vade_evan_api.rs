@@ -16,27 +16,52 @@
 
 #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
 use std::os::raw::c_void;
+use serde::de::DeserializeOwned;
 use vade::Vade;
 
 #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
-use crate::helpers::Credential;
+use crate::api::{IssuerKeyCache, VerificationCache, DEFAULT_ISSUER_KEY_CACHE_SIZE};
+#[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+use crate::helpers::{
+    AttributeMapping,
+    Credential,
+    CredentialError,
+    CredentialOfferOptions,
+    RevocationState,
+};
 #[cfg(feature = "did-sidetree")]
-use crate::helpers::Did;
+use crate::helpers::{is_long_form_did, Did};
 #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
 use crate::helpers::Presentation;
+#[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+use vade_evan_bbs::{BbsCredential, BbsProof};
 #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
 use crate::in3_request_list::ResolveHttpRequest;
 use crate::{
     api::{vade_bundle::get_vade, vade_evan_error::VadeEvanError},
-    helpers::VersionInfo,
+    helpers::{DidDocumentResult, IdentityDidDocument, VersionInfo},
 };
 
 pub const DEFAULT_TARGET: &str = "substrate-dev.trust-trace.com";
 pub const DEFAULT_SIGNER: &str = "local";
 
-fn get_first_result(results: Vec<Option<String>>) -> Result<String, VadeEvanError> {
+/// Gets the first result of a vade call. Plugin errors and calls no plugin has handled are
+/// returned with the name of the called function and the DID or method it has been called for.
+fn get_first_result(
+    results: Result<Vec<Option<String>>, Box<dyn std::error::Error>>,
+    function: &str,
+    did_or_method: &str,
+) -> Result<String, VadeEvanError> {
+    let results = results.map_err(|err| VadeEvanError::PluginFailed {
+        function: function.to_string(),
+        did_or_method: did_or_method.to_string(),
+        source_message: err.to_string(),
+    })?;
     if results.is_empty() {
-        return Err(VadeEvanError::NoResults);
+        return Err(VadeEvanError::NotHandled {
+            function: function.to_string(),
+            did_or_method: did_or_method.to_string(),
+        });
     }
     let empty_result = String::new();
     let result = results[0].as_ref().unwrap_or(&empty_result);
@@ -44,6 +69,46 @@ fn get_first_result(results: Vec<Option<String>>) -> Result<String, VadeEvanErro
     Ok(result.to_string())
 }
 
+/// Parses the DID document of a DID resolution result. A missing, `null` or empty `didDocument`
+/// is reported as [`VadeEvanError::EmptyDidDocument`] instead of a deserialization error.
+pub(crate) fn parse_did_document<T>(did: &str, did_result_str: &str) -> Result<T, VadeEvanError>
+where
+    T: DeserializeOwned,
+{
+    let to_parse_error = |err: serde_json::Error| VadeEvanError::InternalError {
+        source_message: format!("could not parse DID document of {}; {}", did, err),
+    };
+    let did_result: DidDocumentResult<Option<serde_json::Value>> =
+        serde_json::from_str(did_result_str).map_err(to_parse_error)?;
+    match did_result.did_document {
+        Some(serde_json::Value::Object(did_document)) if did_document.is_empty() => {
+            Err(VadeEvanError::EmptyDidDocument {
+                did: did.to_string(),
+            })
+        }
+        Some(did_document) => serde_json::from_value(did_document).map_err(to_parse_error),
+        None => Err(VadeEvanError::EmptyDidDocument {
+            did: did.to_string(),
+        }),
+    }
+}
+
+/// Checks that options for vc-zkp functions are a JSON object with a `type` property, as plugins
+/// use it to decide if they handle a request. Catches malformed options before dispatching them.
+fn fail_on_invalid_vc_zkp_options(options: &str) -> Result<(), VadeEvanError> {
+    let parsed: serde_json::Value =
+        serde_json::from_str(options).map_err(|err| VadeEvanError::InvalidOptions {
+            source_message: format!("options are not valid JSON; {}", err),
+        })?;
+    match parsed.get("type") {
+        Some(serde_json::Value::String(_)) => Ok(()),
+        _ => Err(VadeEvanError::InvalidOptions {
+            source_message: r#"options require a "type" property"#.to_string(),
+        }),
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct VadeEvanConfig<'a> {
     pub target: &'a str,
     pub signer: &'a str,
@@ -53,9 +118,63 @@ pub struct VadeEvanConfig<'a> {
     pub request_function_callback: ResolveHttpRequest,
 }
 
+/// Operation executed by [`VadeEvan::batch`]. Inputs given as `None` are taken from the result
+/// of the previous operation.
+#[derive(Clone, Debug)]
+pub enum BatchOperation {
+    /// Resolves a DID, see [`VadeEvan::did_resolve`].
+    DidResolve { did: Option<String> },
+    /// Runs a custom function, see [`VadeEvan::run_custom_function`].
+    RunCustomFunction {
+        method: String,
+        function: String,
+        options: String,
+        payload: Option<String>,
+    },
+    /// Creates a credential offer, see [`VadeEvan::helper_create_credential_offer`].
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    CreateCredentialOffer {
+        schema_did: String,
+        use_valid_until: bool,
+        issuer_did: String,
+        is_credential_status_included: bool,
+        required_reveal_statements: String,
+        validate_issuer: bool,
+    },
+    /// Creates a credential request, see [`VadeEvan::helper_create_credential_request`].
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    CreateCredentialRequest {
+        issuer_public_key: String,
+        bbs_secret: String,
+        credential_values: String,
+        credential_offer: Option<String>,
+        credential_schema_did: String,
+    },
+    /// Creates a proof request, see [`VadeEvan::helper_create_proof_request_from_proposal`].
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    CreateProofRequestFromProposal { proof_proposal: Option<String> },
+    /// Verifies a credential, see [`VadeEvan::helper_verify_credential_with_subject`]. Results in
+    /// the verified credential, so it can be used by the next operation.
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    VerifyCredential {
+        credential: Option<String>,
+        master_secret: String,
+        expected_subject: Option<String>,
+    },
+}
+
 /// A [`VadeEvan`] instance is your single point of contact for interacting with DIDs and VCs.
 pub struct VadeEvan {
     vade: Vade,
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    issuer_key_cache: IssuerKeyCache,
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    verification_cache: VerificationCache,
+    check_vc_zkp_options: bool,
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    check_signed_id: bool,
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    check_schema_properties: bool,
 }
 
 impl VadeEvan {
@@ -69,13 +188,215 @@ impl VadeEvan {
             #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
             config.request_function_callback,
         ) {
-            Ok(vade) => Ok(Self { vade }),
+            Ok(vade) => Ok(Self {
+                vade,
+                #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+                issuer_key_cache: IssuerKeyCache::new(DEFAULT_ISSUER_KEY_CACHE_SIZE),
+                #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+                verification_cache: VerificationCache::new(0),
+                check_vc_zkp_options: false,
+                #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+                check_signed_id: false,
+                #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+                check_schema_properties: false,
+            }),
             Err(vade_error) => Err(VadeEvanError::InitializationFailed {
                 source_message: vade_error.to_string(),
             }),
         }
     }
 
+    /// Sets the number of issuer public keys kept in cache by this instance. Keys are cached per
+    /// issuer DID and verification method when verifying credentials and presentations and expire
+    /// after five minutes. Use 0 to disable caching.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - maximum number of cached keys
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan.set_issuer_key_cache_size(1000);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub fn set_issuer_key_cache_size(&mut self, size: usize) {
+        self.issuer_key_cache.set_capacity(size);
+    }
+
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub(crate) fn get_issuer_key_cache(&self) -> &IssuerKeyCache {
+        &self.issuer_key_cache
+    }
+
+    /// Enables or disables checking options of `vc_zkp_*` functions before dispatching them to
+    /// plugins. If enabled, options that are not a JSON object with a string `type` property are
+    /// rejected with `VadeEvanError::InvalidOptions`. Disabled by default.
+    ///
+    /// Plugins decide by this `type` if they handle a call, e.g. `{ "type": "bbs" }`, so the check
+    /// reports calls no plugin would handle before dispatching them. Once enabled, all `vc_zkp_*`
+    /// calls of this instance have to pass such options, empty options are rejected as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `true` to check options
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan.set_vc_zkp_options_check(true);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    pub fn set_vc_zkp_options_check(&mut self, enabled: bool) {
+        self.check_vc_zkp_options = enabled;
+    }
+
+    fn check_vc_zkp_options(&self, options: &str) -> Result<(), VadeEvanError> {
+        if self.check_vc_zkp_options {
+            fail_on_invalid_vc_zkp_options(options)?;
+        }
+
+        Ok(())
+    }
+
+    /// Sets the time successful credential verifications are cached by this instance. Repeated
+    /// verifications of an unchanged credential with the same master secret and checks skip
+    /// signature checks within this time, revocation status is checked on every verification.
+    /// Failed verifications are never cached. Caching is disabled by default, use 0 to disable it
+    /// again.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl_seconds` - seconds a successful verification is kept in cache
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan.set_verification_cache_ttl(60);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub fn set_verification_cache_ttl(&mut self, ttl_seconds: u64) {
+        self.verification_cache.set_ttl(ttl_seconds);
+    }
+
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub(crate) fn get_verification_cache(&self) -> &VerificationCache {
+        &self.verification_cache
+    }
+
+    /// Enables rejecting credentials whose `id` is not covered by their signature when verifying
+    /// them with the helper functions of this instance. Absolute IRI ids, like the "uuid:..." ids
+    /// set in unsigned credentials, are signed, so changing them invalidates the signature
+    /// anyway. Other ids are dropped during canonicalization and could be changed after issuance
+    /// unnoticed. Disabled by default, as credentials with such ids would no longer verify.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `true` to reject credentials with unsigned ids
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan.set_signed_id_check(true);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub fn set_signed_id_check(&mut self, enabled: bool) {
+        self.check_signed_id = enabled;
+    }
+
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub(crate) fn get_signed_id_check(&self) -> bool {
+        self.check_signed_id
+    }
+
+    /// Enables checking credentials against their schema when verifying them with the helper
+    /// functions of this instance, so properties not defined in schemas that disallow additional
+    /// properties are rejected. Disabled by default, as it requires resolving the schema of each
+    /// verified credential.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - `true` to check credential properties against their schema
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan.set_schema_property_check(true);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub fn set_schema_property_check(&mut self, enabled: bool) {
+        self.check_schema_properties = enabled;
+    }
+
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub(crate) fn get_schema_property_check(&self) -> bool {
+        self.check_schema_properties
+    }
+
     /// Creates a new DID. May also persist a DID document for it, depending on plugin implementation.
     ///
     /// # Arguments
@@ -109,14 +430,18 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
-        get_first_result(self.vade.did_create(did_method, options, payload).await?)
+        get_first_result(
+            self.vade.did_create(did_method, options, payload).await,
+            "did_create",
+            did_method,
+        )
     }
 
     /// Fetch data about a DID. This usually returns a DID document.
     ///
     /// # Arguments
     ///
-    /// * `did` - did to fetch data for
+    /// * `did` - did to fetch data for, long-form `did:evan` DIDs are resolved locally
     ///
     /// # Example
     ///
@@ -138,7 +463,49 @@ impl VadeEvan {
     /// }
     /// ```
     pub async fn did_resolve(&mut self, did: &str) -> Result<String, VadeEvanError> {
-        get_first_result(self.vade.did_resolve(did).await?)
+        #[cfg(feature = "did-sidetree")]
+        if is_long_form_did(did) {
+            return Did::new(self)?.resolve_long_form(did);
+        }
+        get_first_result(self.vade.did_resolve(did).await, "did_resolve", did)
+    }
+
+    /// Fetches the DID document of a DID and parses it, so callers do not have to unwrap the
+    /// `didDocument` property of the resolve result themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `did` - did to fetch the DID document for
+    ///
+    /// # Returns
+    /// * parsed DID document
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let did_document = vade_evan.did_resolve_typed("did:example:123").await?;
+    ///             println!("got did document for: {}", did_document.id);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    pub async fn did_resolve_typed(
+        &mut self,
+        did: &str,
+    ) -> Result<IdentityDidDocument, VadeEvanError> {
+        let did_result_str = self.did_resolve(did).await?;
+
+        parse_did_document(did, &did_result_str)
     }
 
     /// Updates data related to a DID. May also persist a DID document for it, depending on plugin implementation.
@@ -174,7 +541,11 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
-        get_first_result(self.vade.did_update(did, options, payload).await?)
+        get_first_result(
+            self.vade.did_update(did, options, payload).await,
+            "did_update",
+            did,
+        )
     }
 
     /// Processes a DIDComm message as received, this may prepare a matching response for it
@@ -211,7 +582,11 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
-        get_first_result(self.vade.didcomm_receive(options, payload).await?)
+        get_first_result(
+            self.vade.didcomm_receive(options, payload).await,
+            "didcomm_receive",
+            "",
+        )
     }
 
     /// Processes a DIDComm message and prepares it for sending.
@@ -247,7 +622,11 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
-        get_first_result(self.vade.didcomm_send(options, payload).await?)
+        get_first_result(
+            self.vade.didcomm_send(options, payload).await,
+            "didcomm_send",
+            "",
+        )
     }
 
     /// Gets information about version of `vade_evan` and dependencies prefixed with `evan-`.
@@ -329,29 +708,67 @@ impl VadeEvan {
         issuer_did: &str,
         is_credential_status_included: bool,
         required_reveal_statements: &str,
+    ) -> Result<String, VadeEvanError> {
+        self.helper_create_credential_offer_with_options(
+            schema_did,
+            use_valid_until,
+            issuer_did,
+            is_credential_status_included,
+            required_reveal_statements,
+            &CredentialOfferOptions::default(),
+        )
+        .await
+    }
+
+    /// Creates a new zero-knowledge proof credential offer like
+    /// [`helper_create_credential_offer`](Self::helper_create_credential_offer), with additional
+    /// checks configured by `options`.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_did` - schema to create the offer for
+    /// * `use_valid_until` - true if `validUntil` will be present in credential
+    /// * `issuer_did` - DID of issuer
+    /// * `is_credential_status_included` - true if credentialStatus is included in credential
+    /// * `required_reveal_statements` - required_revealed_statements indices array in searialized form
+    /// * `options` - checks to run before creating the offer, e.g. `validate_issuer` to resolve
+    ///               `issuer_did` and check it for a signing key
+    ///
+    /// # Returns
+    /// * credential offer as JSON serialized [`BbsCredentialOffer`](https://docs.rs/vade_evan_bbs/*/vade_evan_bbs/struct.BbsCredentialOffer.html)
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_create_credential_offer_with_options(
+        &mut self,
+        schema_did: &str,
+        use_valid_until: bool,
+        issuer_did: &str,
+        is_credential_status_included: bool,
+        required_reveal_statements: &str,
+        options: &CredentialOfferOptions,
     ) -> Result<String, VadeEvanError> {
         let mut credential = Credential::new(self)?;
         credential
-            .create_credential_offer(
+            .create_credential_offer_with_options(
                 schema_did,
                 use_valid_until,
                 issuer_did,
                 is_credential_status_included,
                 required_reveal_statements,
+                options,
             )
             .await
             .map_err(|err| err.into())
     }
 
-    /// Creates a credential request. This function is used to create a credential request which is sent to Issuer
+    /// Reads the nonce from a credential offer, that has to be used when requesting the offered
+    /// credential.
     ///
     /// # Arguments
     ///
-    /// * `issuer_public_key` - issuer public key
-    /// * `bbs_secret` - master secret of the holder/receiver
-    /// * `credential_values` - JSON string with cleartext values to be signed in the credential
-    /// * `credential_offer` - JSON string with credential offer by issuer
-    /// * `credential_schema_did` - did for credential schema
+    /// * `offer_str` - credential offer as JSON serialized [`BbsCredentialOffer`](https://docs.rs/vade_evan_bbs/*/vade_evan_bbs/struct.BbsCredentialOffer.html)
+    ///
+    /// # Returns
+    /// * nonce of the offer
     ///
     /// # Example
     ///
@@ -361,30 +778,10 @@ impl VadeEvan {
     ///         use anyhow::Result;
     ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
     ///
-    ///         async fn example() -> Result<()> {
-    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
-    ///             let credential_offer = r#"{
-    ///                "issuer": "did:evan:testcore:0x0d87204c3957d73b68ae28d0af961d3c72403906",
-    ///                "subject": "did:any:abc",
-    ///                "nonce": "QqJR4o6joiApYVXX7JLbRIZBQ9QprlFpewo8GbojIKY=",
-    ///                "credentialMessageCount": 2
-    ///            }"#;
-    ///            let bbs_secret = r#"OASkVMA8q6b3qJuabvgaN9K1mKoqptCv4SCNvRmnWuI="#;
-    ///            let credential_values = r#"{
-    ///                "email": "value@x.com"
-    ///            }"#;
-    ///            let issuer_pub_key = r#"jCv7l26izalfcsFe6j/IqtVlDolo2Y3lNld7xOG63GjSNHBVWrvZQe2O859q9JeVEV4yXtfYofGQSWrMVfgH5ySbuHpQj4fSgLu4xXyFgMidUO1sIe0NHRcXpOorP01o"#;
-    ///
-    ///            let credential_request = vade_evan
-    ///                .helper_create_credential_request(
-    ///                    issuer_pub_key,
-    ///                    bbs_secret,
-    ///                    credential_values,
-    ///                    credential_offer,
-    ///                    "did:evan:EiACv4q04NPkNRXQzQHOEMa3r1p_uINgX75VYP2gaK5ADw",
-    ///                )
-    ///                .await?;
-    ///             println!("created credential request: {}", credential_request);
+    ///         async fn example(offer_str: &str) -> Result<()> {
+    ///             let vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let nonce = vade_evan.helper_get_offer_nonce(offer_str)?;
+    ///             println!("offer nonce: {}", nonce);
     ///             Ok(())
     ///         }
     ///     } else {
@@ -393,10 +790,221 @@ impl VadeEvan {
     /// }
     /// ```
     #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
-    pub async fn helper_create_credential_request(
-        &mut self,
-        issuer_public_key: &str,
-        bbs_secret: &str,
+    pub fn helper_get_offer_nonce(&self, offer_str: &str) -> Result<String, VadeEvanError> {
+        Credential::get_offer_nonce(offer_str).map_err(|err| err.into())
+    }
+
+    /// Checks that a credential offer is consistent with the schema it references, e.g. before a
+    /// holder requests the offered credential. The claims of the offered draft credential
+    /// determine the number of signed messages, so they have to match the schema properties.
+    ///
+    /// # Arguments
+    ///
+    /// * `offer_str` - credential offer as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(offer_str: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan.helper_validate_offer(offer_str).await?;
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_validate_offer(&mut self, offer_str: &str) -> Result<(), VadeEvanError> {
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .validate_offer(offer_str)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Redacts a credential or a presentation for logging. Claim values in
+    /// `credentialSubject.data` are replaced and signatures are truncated, while structural
+    /// fields like issuer, schema and status are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential or presentation as JSON string
+    ///
+    /// # Returns
+    /// * redacted credential or presentation as JSON string
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(credential: &str) -> Result<()> {
+    ///             let vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let redacted = vade_evan.helper_redact_for_logging(credential)?;
+    ///             println!("received credential: {}", redacted);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub fn helper_redact_for_logging(&self, credential: &str) -> Result<String, VadeEvanError> {
+        Credential::redact_for_logging(credential).map_err(|err| err.into())
+    }
+
+    /// Gets the index of the BBS message, that contains an attribute of a credential, e.g. to
+    /// build `requiredRevealStatements` or selectively disclose attributes. The index accounts
+    /// for the hidden messages signed before the credential's nquads.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential as JSON string, with or without proof
+    /// * `attribute_name` - name of the attribute, e.g. "bio"
+    ///
+    /// # Returns
+    /// * index of the message
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(credential: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let index = vade_evan.helper_attribute_index(credential, "bio").await?;
+    ///             println!("bio is signed in message {}", index);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_attribute_index(
+        &mut self,
+        credential: &str,
+        attribute_name: &str,
+    ) -> Result<usize, VadeEvanError> {
+        let credential_helper = Credential::new(self)?;
+        credential_helper
+            .attribute_index(credential, attribute_name)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Lists the nquad and BBS message index of each attribute in `credentialSubject.data`, so
+    /// issuers and verifiers can check that they agree on the canonicalization of a credential,
+    /// e.g. when debugging message count mismatches.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential as JSON string, with or without proof
+    ///
+    /// # Returns
+    /// * mapping for each attribute, in order of the credential data
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(credential: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             for mapping in vade_evan.helper_attribute_mapping(credential).await? {
+    ///                 println!("{} is signed in message {}", mapping.attribute, mapping.message_index);
+    ///             }
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_attribute_mapping(
+        &mut self,
+        credential: &str,
+    ) -> Result<Vec<AttributeMapping>, VadeEvanError> {
+        let credential_helper = Credential::new(self)?;
+        credential_helper
+            .attribute_mapping(credential)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Creates a credential request. This function is used to create a credential request which is sent to Issuer
+    ///
+    /// # Arguments
+    ///
+    /// * `issuer_public_key` - issuer public key
+    /// * `bbs_secret` - master secret of the holder/receiver
+    /// * `credential_values` - JSON string with cleartext values to be signed in the credential
+    /// * `credential_offer` - JSON string with credential offer by issuer
+    /// * `credential_schema_did` - did for credential schema
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let credential_offer = r#"{
+    ///                "issuer": "did:evan:testcore:0x0d87204c3957d73b68ae28d0af961d3c72403906",
+    ///                "subject": "did:any:abc",
+    ///                "nonce": "QqJR4o6joiApYVXX7JLbRIZBQ9QprlFpewo8GbojIKY=",
+    ///                "credentialMessageCount": 2
+    ///            }"#;
+    ///            let bbs_secret = r#"OASkVMA8q6b3qJuabvgaN9K1mKoqptCv4SCNvRmnWuI="#;
+    ///            let credential_values = r#"{
+    ///                "email": "value@x.com"
+    ///            }"#;
+    ///            let issuer_pub_key = r#"jCv7l26izalfcsFe6j/IqtVlDolo2Y3lNld7xOG63GjSNHBVWrvZQe2O859q9JeVEV4yXtfYofGQSWrMVfgH5ySbuHpQj4fSgLu4xXyFgMidUO1sIe0NHRcXpOorP01o"#;
+    ///
+    ///            let credential_request = vade_evan
+    ///                .helper_create_credential_request(
+    ///                    issuer_pub_key,
+    ///                    bbs_secret,
+    ///                    credential_values,
+    ///                    credential_offer,
+    ///                    "did:evan:EiACv4q04NPkNRXQzQHOEMa3r1p_uINgX75VYP2gaK5ADw",
+    ///                )
+    ///                .await?;
+    ///             println!("created credential request: {}", credential_request);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_create_credential_request(
+        &mut self,
+        issuer_public_key: &str,
+        bbs_secret: &str,
         credential_values: &str,
         credential_offer: &str,
         credential_schema_did: &str,
@@ -414,13 +1022,475 @@ impl VadeEvan {
             .map_err(|err| err.into())
     }
 
-    /// Verifies a given credential by checking if given master secret was incorporated
-    /// into proof and if proof was signed with issuers public key.
+    /// Verifies a given credential by checking if given master secret was incorporated
+    /// into proof and if proof was signed with issuers public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential to verify as serialized JSON
+    /// * `master_secret` - master secret incorporated as a blinded value into the proof of the credential
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let credential = r###"{
+    ///                 "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
+    ///                 "type": [
+    ///                     "VerifiableCredential"
+    ///                 ],
+    ///                 "proof": {
+    ///                     "type": "BbsBlsSignature2020",
+    ///                     "created": "2023-02-01T14:08:17.000Z",
+    ///                     "signature": "kvSyi40dnZ5S3/mSxbSUQGKLpyMXDQNLCPtwDGM9GsnNNKF7MtaFHXIbvXaVXku0EY/n2uNMQ2bmK2P0KEmzgbjRHtzUOWVdfAnXnVRy8/UHHIyJR471X6benfZk8KG0qVqy+w67z9g628xRkFGA5Q==",
+    ///                     "proofPurpose": "assertionMethod",
+    ///                     "verificationMethod": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA#bbs-key-1",
+    ///                     "credentialMessageCount": 13,
+    ///                     "requiredRevealStatements": []
+    ///                 },
+    ///                 "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+    ///                 "@context": [
+    ///                     "https://www.w3.org/2018/credentials/v1",
+    ///                     "https://schema.org/",
+    ///                     "https://w3id.org/vc-revocation-list-2020/v1"
+    ///                 ],
+    ///                 "issuanceDate": "2023-02-01T14:08:09.849Z",
+    ///                 "credentialSchema": {
+    ///                     "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
+    ///                     "type": "EvanVCSchema"
+    ///                 },
+    ///                 "credentialStatus": {
+    ///                     "id": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA#4",
+    ///                     "type": "RevocationList2020Status",
+    ///                     "revocationListIndex": "4",
+    ///                     "revocationListCredential": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA"
+    ///                 },
+    ///                 "credentialSubject": {
+    ///                     "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+    ///                     "data": {
+    ///                         "bio": "biography"
+    ///                     }
+    ///                 }
+    ///             }"###;
+    ///             let master_secret = "QyRmu33oIQFNW+dSI5wex3u858Ra7yx5O1tsxJgQvu8=";
+    ///
+    ///             // verify the credential issuer
+    ///             vade_evan
+    ///                 .helper_verify_credential(credential, master_secret)
+    ///                 .await?;
+    ///
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_verify_credential(
+        &mut self,
+        credential: &str,
+        master_secret: &str,
+    ) -> Result<(), VadeEvanError> {
+        self.helper_verify_credential_with_subject(credential, master_secret, None)
+            .await
+    }
+
+    /// Verifies a credential like [`helper_verify_credential`](Self::helper_verify_credential),
+    /// and optionally checks that it has been issued to the holder presenting it.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential to verify as serialized JSON
+    /// * `master_secret` - master secret incorporated as a blinded value into the proof of the credential
+    /// * `expected_subject` - if given, `credentialSubject.id` of the credential has to match this DID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(credential: &str, master_secret: &str, holder_did: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan
+    ///                 .helper_verify_credential_with_subject(credential, master_secret, Some(holder_did))
+    ///                 .await?;
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_verify_credential_with_subject(
+        &mut self,
+        credential: &str,
+        master_secret: &str,
+        expected_subject: Option<&str>,
+    ) -> Result<(), VadeEvanError> {
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .verify_credential(credential, master_secret, expected_subject)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Verifies a credential like [`helper_verify_credential`](Self::helper_verify_credential)
+    /// and returns the parsed credential, so callers do not have to parse it again to read its
+    /// claims.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential to verify as JSON string
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    ///
+    /// # Returns
+    /// * verified [`BbsCredential`](https://docs.rs/vade_evan_bbs/*/vade_evan_bbs/struct.BbsCredential.html),
+    ///   with its first proof if it has multiple
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(credential: &str, master_secret: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let credential = vade_evan
+    ///                 .helper_verify_credential_and_parse(credential, master_secret)
+    ///                 .await?;
+    ///             println!("verified credential issued by {}", credential.issuer);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_verify_credential_and_parse(
+        &mut self,
+        credential: &str,
+        master_secret: &str,
+    ) -> Result<BbsCredential, VadeEvanError> {
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .verify_credential_and_parse(credential, master_secret)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Verifies the signature of a credential against a given issuer public key, without
+    /// resolving the issuer DID or the revocation list of the credential.
+    /// Revocation status is not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential to verify as serialized JSON
+    /// * `issuer_public_key` - base64 encoded bbs public key of the issuer
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let credential = r###"{
+    ///                 "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
+    ///                 "type": [
+    ///                     "VerifiableCredential"
+    ///                 ],
+    ///                 "proof": {
+    ///                     "type": "BbsBlsSignature2020",
+    ///                     "created": "2023-02-01T14:08:17.000Z",
+    ///                     "signature": "kvSyi40dnZ5S3/mSxbSUQGKLpyMXDQNLCPtwDGM9GsnNNKF7MtaFHXIbvXaVXku0EY/n2uNMQ2bmK2P0KEmzgbjRHtzUOWVdfAnXnVRy8/UHHIyJR471X6benfZk8KG0qVqy+w67z9g628xRkFGA5Q==",
+    ///                     "proofPurpose": "assertionMethod",
+    ///                     "verificationMethod": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA#bbs-key-1",
+    ///                     "credentialMessageCount": 13,
+    ///                     "requiredRevealStatements": []
+    ///                 },
+    ///                 "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+    ///                 "@context": [
+    ///                     "https://www.w3.org/2018/credentials/v1",
+    ///                     "https://schema.org/",
+    ///                     "https://w3id.org/vc-revocation-list-2020/v1"
+    ///                 ],
+    ///                 "issuanceDate": "2023-02-01T14:08:09.849Z",
+    ///                 "credentialSchema": {
+    ///                     "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
+    ///                     "type": "EvanVCSchema"
+    ///                 },
+    ///                 "credentialStatus": {
+    ///                     "id": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA#4",
+    ///                     "type": "RevocationList2020Status",
+    ///                     "revocationListIndex": "4",
+    ///                     "revocationListCredential": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA"
+    ///                 },
+    ///                 "credentialSubject": {
+    ///                     "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+    ///                     "data": {
+    ///                         "bio": "biography"
+    ///                     }
+    ///                 }
+    ///             }"###;
+    ///             let master_secret = "QyRmu33oIQFNW+dSI5wex3u858Ra7yx5O1tsxJgQvu8=";
+    ///
+    ///             let issuer_public_key = "qWZ7EGhzYsSlBq4mLhNal6cHXBD88ZfncdbEWQoue6SaAbZ7k56IxsjcvuXD6LGYDgMgtjTHnBraaMRiwJVBJenXgOT8nto7ZUTO/TvCXwtyPMzGrLM5JNJdEaPP4QJN";
+    ///
+    ///             // verify the credential signature with a trusted issuer key
+    ///             vade_evan
+    ///                 .helper_verify_credential_with_key(credential, issuer_public_key, master_secret)
+    ///                 .await?;
+    ///
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_verify_credential_with_key(
+        &mut self,
+        credential: &str,
+        issuer_public_key: &str,
+        master_secret: &str,
+    ) -> Result<(), VadeEvanError> {
+        let credential_helper = Credential::new(self)?;
+        credential_helper
+            .verify_credential_with_key(credential, issuer_public_key, master_secret)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Verifies a credential like [`helper_verify_credential`](Self::helper_verify_credential),
+    /// but tries multiple verification methods of the issuer instead of the one named in the
+    /// proof, e.g. to verify credentials signed before the issuer rotated its keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential to verify as JSON string
+    /// * `candidate_method_ids` - ids of issuer verification methods to try as serialized JSON
+    ///   array, e.g. `["#bbs-key-1","#bbs-key-2"]`
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    ///
+    /// # Returns
+    /// * `String` - id of the verification method the signature has been verified with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let credential = r#"{ "type": ["VerifiableCredential"] }"#;
+    ///             let master_secret = "QyRmu33oIQFNW+dSI5wex3u858Ra7yx5O1tsxJgQvu8=";
+    ///             let method_id = vade_evan
+    ///                 .helper_verify_credential_any_key(
+    ///                     credential,
+    ///                     r#"["#bbs-key-1","#bbs-key-2"]"#,
+    ///                     master_secret,
+    ///                 )
+    ///                 .await?;
+    ///             println!("credential has been signed with {}", method_id);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_verify_credential_any_key(
+        &mut self,
+        credential: &str,
+        candidate_method_ids: &str,
+        master_secret: &str,
+    ) -> Result<String, VadeEvanError> {
+        let candidate_method_ids: Vec<String> =
+            serde_json::from_str(candidate_method_ids).map_err(CredentialError::from)?;
+        let candidate_method_ids: Vec<&str> =
+            candidate_method_ids.iter().map(String::as_str).collect();
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .verify_credential_any_key(credential, &candidate_method_ids, master_secret)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Verifies a credential signature against already canonicalized N-Quads of the credential
+    /// without its proof, skipping the JSON-LD processing done by
+    /// [`helper_verify_credential_with_key`](Self::helper_verify_credential_with_key).
+    ///
+    /// The caller is responsible for the N-Quads being the correct canonicalization of the
+    /// credential, in their original order. Neither the credential's `@context` nor its
+    /// timestamps or revocation status are checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `nquads` - canonicalized N-Quads of the credential without proof
+    /// * `proof` - proof of the credential
+    /// * `issuer_public_key` - base64 encoded bbs public key of the issuer
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///         use vade_evan_bbs::BbsProof;
+    ///
+    ///         async fn example(nquads: &[String], proof: &BbsProof, issuer_public_key: &str, master_secret: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan
+    ///                 .helper_verify_credential_with_nquads(nquads, proof, issuer_public_key, master_secret)
+    ///                 .await?;
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_verify_credential_with_nquads(
+        &mut self,
+        nquads: &[String],
+        proof: &BbsProof,
+        issuer_public_key: &str,
+        master_secret: &str,
+    ) -> Result<(), VadeEvanError> {
+        let credential_helper = Credential::new(self)?;
+        credential_helper
+            .verify_credential_with_nquads(nquads, proof, issuer_public_key, master_secret)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Verifies a credential with a derived `BbsBlsSignatureProof2020` proof, e.g. taken from a
+    /// presentation, against a given issuer public key. All attributes of the credential have to
+    /// be revealed. Revocation status is not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential with derived proof as JSON string
+    /// * `issuer_public_key` - base64 encoded bbs public key of the issuer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(credential: &str, issuer_public_key: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan
+    ///                 .helper_verify_derived_credential_with_key(credential, issuer_public_key)
+    ///                 .await?;
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_verify_derived_credential_with_key(
+        &mut self,
+        credential: &str,
+        issuer_public_key: &str,
+    ) -> Result<(), VadeEvanError> {
+        let credential_helper = Credential::new(self)?;
+        credential_helper
+            .verify_derived_credential_with_key(credential, issuer_public_key)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Verifies a credential against an issuer DID document held by the caller, e.g. provided by a
+    /// trust registry, instead of resolving the issuer DID. The verification method has to be
+    /// authorized for the proof purpose of the credential in the given document. Revocation
+    /// status is not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential to verify as JSON string
+    /// * `issuer_document` - DID document of the credential issuer
+    /// * `verification_method_id` - id of the issuer's verification method, e.g. "#bbs-key-1"
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{IdentityDidDocument, VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(credential: &str, issuer_document: &IdentityDidDocument, master_secret: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan
+    ///                 .helper_verify_credential_with_document(credential, issuer_document, "#bbs-key-1", master_secret)
+    ///                 .await?;
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_verify_credential_with_document(
+        &mut self,
+        credential: &str,
+        issuer_document: &IdentityDidDocument,
+        verification_method_id: &str,
+        master_secret: &str,
+    ) -> Result<(), VadeEvanError> {
+        let credential_helper = Credential::new(self)?;
+        credential_helper
+            .verify_credential_with_document(
+                credential,
+                issuer_document,
+                verification_method_id,
+                master_secret,
+            )
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Resolves issuer DIDs and caches the public keys of their verification methods, so first
+    /// verifications of their credentials do not have to wait for DID resolution. DIDs are
+    /// resolved one after another, each of them for at most 10 seconds. Has no effect if the
+    /// issuer key cache is disabled, see
+    /// [`set_issuer_key_cache_size`](Self::set_issuer_key_cache_size).
     ///
     /// # Arguments
     ///
-    /// * `credential` - credential to verify as serialized JSON
-    /// * `master_secret` - master secret incorporated as a blinded value into the proof of the credential
+    /// * `issuer_dids` - DIDs of issuers to cache keys for
     ///
     /// # Example
     ///
@@ -432,70 +1502,174 @@ impl VadeEvan {
     ///
     ///         async fn example() -> Result<()> {
     ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
-    ///             let credential = r###"{
-    ///                 "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
-    ///                 "type": [
-    ///                     "VerifiableCredential"
-    ///                 ],
-    ///                 "proof": {
-    ///                     "type": "BbsBlsSignature2020",
-    ///                     "created": "2023-02-01T14:08:17.000Z",
-    ///                     "signature": "kvSyi40dnZ5S3/mSxbSUQGKLpyMXDQNLCPtwDGM9GsnNNKF7MtaFHXIbvXaVXku0EY/n2uNMQ2bmK2P0KEmzgbjRHtzUOWVdfAnXnVRy8/UHHIyJR471X6benfZk8KG0qVqy+w67z9g628xRkFGA5Q==",
-    ///                     "proofPurpose": "assertionMethod",
-    ///                     "verificationMethod": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA#bbs-key-1",
-    ///                     "credentialMessageCount": 13,
-    ///                     "requiredRevealStatements": []
-    ///                 },
-    ///                 "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
-    ///                 "@context": [
-    ///                     "https://www.w3.org/2018/credentials/v1",
-    ///                     "https://schema.org/",
-    ///                     "https://w3id.org/vc-revocation-list-2020/v1"
-    ///                 ],
-    ///                 "issuanceDate": "2023-02-01T14:08:09.849Z",
-    ///                 "credentialSchema": {
-    ///                     "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
-    ///                     "type": "EvanVCSchema"
-    ///                 },
-    ///                 "credentialStatus": {
-    ///                     "id": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA#4",
-    ///                     "type": "RevocationList2020Status",
-    ///                     "revocationListIndex": "4",
-    ///                     "revocationListCredential": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA"
-    ///                 },
-    ///                 "credentialSubject": {
-    ///                     "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
-    ///                     "data": {
-    ///                         "bio": "biography"
-    ///                     }
-    ///                 }
-    ///             }"###;
-    ///             let master_secret = "QyRmu33oIQFNW+dSI5wex3u858Ra7yx5O1tsxJgQvu8=";
-    ///
-    ///             // verify the credential issuer
     ///             vade_evan
-    ///                 .helper_verify_credential(credential, master_secret)
+    ///                 .helper_warm_cache(&["did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA"])
     ///                 .await?;
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_warm_cache(&mut self, issuer_dids: &[&str]) -> Result<(), VadeEvanError> {
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .warm_cache(issuer_dids)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Caches issuer public keys like [`helper_warm_cache`](Self::helper_warm_cache), but gives
+    /// up resolving a single DID after `timeout`. DIDs that failed or timed out are listed in the
+    /// returned error.
+    ///
+    /// # Arguments
+    ///
+    /// * `issuer_dids` - DIDs of issuers to cache keys for
+    /// * `timeout` - maximum time to wait for the resolution of a single DID
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_warm_cache_with_timeout(
+        &mut self,
+        issuer_dids: &[&str],
+        timeout: std::time::Duration,
+    ) -> Result<(), VadeEvanError> {
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .warm_cache_with_timeout(issuer_dids, timeout)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Resolves an issuer public key and checks that it can be used to verify credentials with
+    /// the given number of messages, so callers can fail before verifying a credential.
     ///
+    /// # Arguments
+    ///
+    /// * `issuer_did` - DID of the issuer
+    /// * `verification_method_id` - id of the issuer's verification method, e.g. "#bbs-key-1"
+    /// * `expected_message_count` - number of signed messages, including the master secret
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan
+    ///                 .helper_validate_issuer_key(
+    ///                     "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+    ///                     "#bbs-key-1",
+    ///                     13,
+    ///                 )
+    ///                 .await?;
     ///             Ok(())
     ///         }
     ///     } else {
     ///         // currently no example for target-c-sdk and c-lib/target-java-lib
     ///     }
     /// }
+    /// ```
     #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
-    pub async fn helper_verify_credential(
+    pub async fn helper_validate_issuer_key(
         &mut self,
-        credential: &str,
-        master_secret: &str,
+        issuer_did: &str,
+        verification_method_id: &str,
+        expected_message_count: usize,
     ) -> Result<(), VadeEvanError> {
         let mut credential_helper = Credential::new(self)?;
         credential_helper
-            .verify_credential(credential, master_secret)
+            .validate_issuer_key(issuer_did, verification_method_id, expected_message_count)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Wraps a credential or a presentation in a JWT verifiable presentation signed by the holder.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential_or_presentation` - credential or presentation as JSON string
+    /// * `holder_key` - hex encoded private key of the holder to sign the JWT with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let holder_key = "dfcdcb6d5d09411ae9cbe1b0fd9751ba8803dd4b276d5bf9488ae4ede2669106";
+    ///             let jwt_vp = vade_evan
+    ///                 .helper_credential_to_jwt_vp(r#"{ "type": ["VerifiableCredential"] }"#, holder_key)
+    ///                 .await?;
+    ///             println!("JWT VP: {}", jwt_vp);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_credential_to_jwt_vp(
+        &mut self,
+        credential_or_presentation: &str,
+        holder_key: &str,
+    ) -> Result<String, VadeEvanError> {
+        let credential_helper = Credential::new(self)?;
+        credential_helper
+            .to_jwt_vp(credential_or_presentation, holder_key)
             .await
             .map_err(|err| err.into())
     }
 
+    /// Extracts the credential or presentation from a JWT verifiable presentation and checks that
+    /// it has been signed by the expected holder.
+    ///
+    /// # Arguments
+    ///
+    /// * `jwt_vp` - JWT verifiable presentation
+    /// * `holder_address` - address of the key the holder signed the JWT with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(jwt_vp: &str, holder_address: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let credential = vade_evan.helper_credential_from_jwt_vp(jwt_vp, holder_address)?;
+    ///             println!("credential: {}", credential);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub fn helper_credential_from_jwt_vp(
+        &mut self,
+        jwt_vp: &str,
+        holder_address: &str,
+    ) -> Result<String, VadeEvanError> {
+        let credential_helper = Credential::new(self)?;
+        credential_helper
+            .from_jwt_vp(jwt_vp, holder_address)
+            .map_err(|err| err.into())
+    }
+
     /// Proposes to share a proof for a credential.
     /// The proof proposal consists of the fields the prover wants to reveal per schema.
     ///
@@ -639,21 +1813,128 @@ impl VadeEvan {
     ) -> Result<String, VadeEvanError> {
         let mut presentation_helper = Presentation::new(self)?;
         presentation_helper
-            .create_proof_request(schema_did, revealed_attributes)
+            .create_proof_request(schema_did, revealed_attributes)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Creates a presentation.
+    /// The presentation has proof and requested credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof_request` - proof request for presentation
+    /// * `credential` - credential to be shared in presentation
+    /// * `master_secret` - user's master secret
+    /// * `signing_key` - users secp256k1 private signing key
+    /// * `revealed_attributes` - list of names of revealed attributes in specified schema,
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         const SIGNER_PRIVATE_KEY: &str =
+    ///         "dfcdcb6d5d09411ae9cbe1b0fd9751ba8803dd4b276d5bf9488ae4ede2669106";
+    ///         const MASTER_SECRET: &str = "QyRmu33oIQFNW+dSI5wex3u858Ra7yx5O1tsxJgQvu8=";
+    ///         const PROVER_DID: &str = "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA";
+    ///         const SCHEMA_DID: &str = "did:evan:EiBrPL8Yif5NWHOzbKvyh1PX1wKVlWvIa6nTG1v8PXytvg"; // evan.address
+    ///         const CREDENTIAL: &str = r###"{
+    ///             "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
+    ///             "type": [
+    ///                 "VerifiableCredential"
+    ///             ],
+    ///             "proof": {
+    ///                 "type": "BbsBlsSignature2020",
+    ///                 "created": "2023-02-01T14:08:17.000Z",
+    ///                 "signature": "kvSyi40dnZ5S3/mSxbSUQGKLpyMXDQNLCPtwDGM9GsnNNKF7MtaFHXIbvXaVXku0EY/n2uNMQ2bmK2P0KEmzgbjRHtzUOWVdfAnXnVRy8/UHHIyJR471X6benfZk8KG0qVqy+w67z9g628xRkFGA5Q==",
+    ///                 "proofPurpose": "assertionMethod",
+    ///                 "verificationMethod": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA#bbs-key-1",
+    ///                 "credentialMessageCount": 13,
+    ///                 "requiredRevealStatements": []
+    ///             },
+    ///             "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+    ///             "@context": [
+    ///                 "https://www.w3.org/2018/credentials/v1",
+    ///                 "https://schema.org/",
+    ///                 "https://w3id.org/vc-revocation-list-2020/v1"
+    ///             ],
+    ///             "issuanceDate": "2023-02-01T14:08:09.849Z",
+    ///             "credentialSchema": {
+    ///                 "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
+    ///                 "type": "EvanVCSchema"
+    ///             },
+    ///             "credentialStatus": {
+    ///                 "id": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA#4",
+    ///                 "type": "RevocationList2020Status",
+    ///                 "revocationListIndex": "4",
+    ///                 "revocationListCredential": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA"
+    ///             },
+    ///             "credentialSubject": {
+    ///                 "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+    ///                 "data": {
+    ///                     "bio": "biography"
+    ///                 }
+    ///             }
+    ///         }"###;
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let revealed_attributes = Some(r#"["zip", "country"]"#);
+    ///             let proof_request_str = vade_evan
+    ///                 .helper_create_proof_request(SCHEMA_DID, revealed_attributes)
+    ///                 .await?;
+    ///
+    ///             let presentation_result = vade_evan
+    ///               .helper_create_presentation(
+    ///                   &proof_request_str,
+    ///                   CREDENTIAL,
+    ///                   MASTER_SECRET,
+    ///                   SIGNER_PRIVATE_KEY,
+    ///                   PROVER_DID,
+    ///                   None,
+    ///                )
+    ///                .await;
+    ///
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_create_presentation(
+        &mut self,
+        proof_request_str: &str,
+        credential_str: &str,
+        master_secret: &str,
+        signing_key: &str,
+        prover_did: &str,
+        revealed_attributes: Option<&str>,
+    ) -> Result<String, VadeEvanError> {
+        let mut presentation_helper = Presentation::new(self)?;
+        presentation_helper
+            .create_presentation(
+                proof_request_str,
+                credential_str,
+                master_secret,
+                signing_key,
+                prover_did,
+                revealed_attributes,
+            )
             .await
             .map_err(|err| err.into())
     }
 
-    /// Creates a presentation.
-    /// The presentation has proof and requested credentials.
+    /// Verifies a presentation.
+    /// The function checks if the presentation is valid against the provided proof request.
     ///
     /// # Arguments
     ///
-    /// * `proof_request` - proof request for presentation
-    /// * `credential` - credential to be shared in presentation
-    /// * `master_secret` - user's master secret
-    /// * `signing_key` - users secp256k1 private signing key
-    /// * `revealed_attributes` - list of names of revealed attributes in specified schema,
+    /// * `presentation_str` - verifiable presentation from the holder as JSON string
+    /// * `proof_request_str` - proof request from the verifier as JSON string
     ///
     /// # Example
     ///
@@ -713,7 +1994,7 @@ impl VadeEvan {
     ///                 .helper_create_proof_request(SCHEMA_DID, revealed_attributes)
     ///                 .await?;
     ///
-    ///             let presentation_result = vade_evan
+    ///             let presentation_str = vade_evan
     ///               .helper_create_presentation(
     ///                   &proof_request_str,
     ///                   CREDENTIAL,
@@ -722,8 +2003,9 @@ impl VadeEvan {
     ///                   PROVER_DID,
     ///                   None,
     ///                )
-    ///                .await;
-    ///
+    ///                .await?;
+    ///             let verify_result = vade_evan.helper_verify_presentation(&presentation_str, &proof_request_str).await;
+    ///             assert!(verify_result.is_ok());
     ///             Ok(())
     ///         }
     ///     } else {
@@ -731,37 +2013,30 @@ impl VadeEvan {
     ///     }
     /// }
     #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
-    pub async fn helper_create_presentation(
+    pub async fn helper_verify_presentation(
         &mut self,
+        presentation_str: &str,
         proof_request_str: &str,
-        credential_str: &str,
-        master_secret: &str,
-        signing_key: &str,
-        prover_did: &str,
-        revealed_attributes: Option<&str>,
     ) -> Result<String, VadeEvanError> {
         let mut presentation_helper = Presentation::new(self)?;
         presentation_helper
-            .create_presentation(
-                proof_request_str,
-                credential_str,
-                master_secret,
-                signing_key,
-                prover_did,
-                revealed_attributes,
-            )
+            .verify_presentation(presentation_str, proof_request_str)
             .await
             .map_err(|err| err.into())
     }
 
-    /// Verifies a presentation.
-    /// The function checks if the presentation is valid against the provided proof request.
+    /// Verifies a presentation and checks that it reveals all attributes requested in the proof
+    /// request. In addition to the checks done by `helper_verify_presentation`, every requested
+    /// attribute has to be present in the revealed data of the presented credentials.
     ///
     /// # Arguments
     ///
     /// * `presentation_str` - verifiable presentation from the holder as JSON string
     /// * `proof_request_str` - proof request from the verifier as JSON string
     ///
+    /// # Returns
+    /// * `String` - revealed attributes as JSON, mapping schema DIDs to attribute names and values
+    ///
     /// # Example
     ///
     /// ```
@@ -830,8 +2105,13 @@ impl VadeEvan {
     ///                   None,
     ///                )
     ///                .await?;
-    ///             let verify_result = vade_evan.helper_verify_presentation(&presentation_str, &proof_request_str).await;
-    ///             assert!(verify_result.is_ok());
+    ///             match vade_evan
+    ///                 .helper_verify_presentation_against_request(&presentation_str, &proof_request_str)
+    ///                 .await
+    ///             {
+    ///                 Ok(revealed_claims) => println!("revealed claims: {}", revealed_claims),
+    ///                 Err(err) => println!("presentation rejected: {}", err),
+    ///             };
     ///             Ok(())
     ///         }
     ///     } else {
@@ -839,14 +2119,14 @@ impl VadeEvan {
     ///     }
     /// }
     #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
-    pub async fn helper_verify_presentation(
+    pub async fn helper_verify_presentation_against_request(
         &mut self,
         presentation_str: &str,
         proof_request_str: &str,
     ) -> Result<String, VadeEvanError> {
         let mut presentation_helper = Presentation::new(self)?;
         presentation_helper
-            .verify_presentation(presentation_str, proof_request_str)
+            .verify_presentation_against_request(presentation_str, proof_request_str)
             .await
             .map_err(|err| err.into())
     }
@@ -940,6 +2220,199 @@ impl VadeEvan {
             .map_err(|err| err.into())
     }
 
+    /// Revokes multiple credentials of a revocation list with a single DID update, e.g. to
+    /// revoke a batch of leaked credentials at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `revocation_list_did` - DID of the revocation list
+    /// * `indices` - revocation list indices of the credentials to revoke
+    /// * `update_key_jwk` - public key in jwk format to sign did update
+    ///
+    /// # Returns
+    /// * result of the DID update of the revocation list
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example(update_key_jwk: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             vade_evan
+    ///                 .helper_revoke_credentials(
+    ///                     "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA",
+    ///                     &[7, 8, 20],
+    ///                     update_key_jwk,
+    ///                 )
+    ///                 .await?;
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_revoke_credentials(
+        &mut self,
+        revocation_list_did: &str,
+        indices: &[u32],
+        update_key_jwk: &str,
+    ) -> Result<String, VadeEvanError> {
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .revoke_many(revocation_list_did, indices, update_key_jwk)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Checks the revocation status of a credential without verifying its signature, e.g. for
+    /// holders that want to know if a credential can still be presented.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential` - credential to check as JSON string
+    ///
+    /// # Returns
+    /// * `RevocationState` - `Active` for credentials without `credentialStatus`, `Unknown` if
+    ///   the revocation list cannot be resolved
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{RevocationState, VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let credential = r###"{
+    ///                 "credentialStatus": {
+    ///                     "id": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA#4",
+    ///                     "type": "RevocationList2020Status",
+    ///                     "revocationListIndex": "4",
+    ///                     "revocationListCredential": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA"
+    ///                 }
+    ///             }"###;
+    ///
+    ///             let state = vade_evan.helper_revocation_status(credential).await?;
+    ///             if state == RevocationState::Revoked {
+    ///                 println!("credential has been revoked");
+    ///             }
+    ///
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_revocation_status(
+        &mut self,
+        credential: &str,
+    ) -> Result<RevocationState, VadeEvanError> {
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .revocation_status(credential)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Finds the first index in a revocation list that is not flagged as revoked, so it can be
+    /// used as `revocationListIndex` for a new credential.
+    ///
+    /// Note that the revocation list only keeps track of revoked indices, so issuers still have
+    /// to make sure not to assign an index to multiple active credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `revocation_list_did` - DID of the revocation list to check
+    ///
+    /// # Returns
+    /// * first free index in revocation list
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let index = vade_evan
+    ///                 .helper_next_free_revocation_index("did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA")
+    ///                 .await?;
+    ///             println!("next credential can use revocation list index {}", index);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_next_free_revocation_index(
+        &mut self,
+        revocation_list_did: &str,
+    ) -> Result<u32, VadeEvanError> {
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .next_free_revocation_index(revocation_list_did)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Lists the indices of all credentials flagged as revoked in a revocation list, supports
+    /// RevocationList2020 and StatusList2021 lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `revocation_list_did` - DID of the revocation list to check
+    ///
+    /// # Returns
+    /// * revoked indices in ascending order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let revoked_indices = vade_evan
+    ///                 .helper_list_revoked_indices("did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA")
+    ///                 .await?;
+    ///             println!("revoked indices: {:?}", revoked_indices);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_list_revoked_indices(
+        &mut self,
+        revocation_list_did: &str,
+    ) -> Result<Vec<u32>, VadeEvanError> {
+        let mut credential_helper = Credential::new(self)?;
+        credential_helper
+            .list_revoked_indices(revocation_list_did)
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// Creates a new zero-knowledge proof self issued credential.
     /// `create_self_issued_credential` function combines `vc_zkp_create_credential_offer`,
     /// `vc_zkp_create_credential_request`, `vc_zkp_issue_credential` and `vc_zkp_finish_credential`
@@ -1031,9 +2504,81 @@ impl VadeEvan {
             .map_err(|err| err.into())
     }
 
+    /// Re-issues a credential with the same claims, subject and revocation status, but a new
+    /// validity window, e.g. for holders of credentials that are about to expire.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_credential` - credential to refresh as JSON string
+    /// * `issuer_did` - DID of the issuer, its key "#bbs-key-1" is used to sign the credential
+    /// * `issuer_key` - BBS private key of the issuer
+    /// * `bbs_secret` - master secret of the holder
+    /// * `new_valid_until` - new expiration date, e.g. "2024-12-03T14:23:42.120Z"
+    ///
+    /// # Returns
+    /// * credential as JSON serialized [`BbsCredential`](https://docs.rs/vade_evan_bbs/*/vade_evan_bbs/struct.BbsCredential.html)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         const BBS_SECRET: &str = "GRsdzRB0pf/8MKP/ZBOM2BEV1A8DIDfmLh8T3b1hPKc=";
+    ///         const BBS_PRIVATE_KEY: &str = "WWTZW8pkz35UnvsUCEsof2CJmNHaJQ/X+B5xjWcHr/I=";
+    ///         const ISSUER_DID: &str = "did:evan:EiAOD3RUcQrRXNZIR8BIEXuGvixcUj667_5fdeX-Sp3PpA";
+    ///
+    ///         async fn example(old_credential: &str) -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let credential = vade_evan
+    ///                 .helper_refresh_credential(
+    ///                     old_credential,
+    ///                     ISSUER_DID,
+    ///                     BBS_PRIVATE_KEY,
+    ///                     BBS_SECRET,
+    ///                     "2031-12-03T14:23:42.120Z",
+    ///                 )
+    ///                 .await?;
+    ///             println!("refreshed credential: {}", credential);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    pub async fn helper_refresh_credential(
+        &mut self,
+        old_credential: &str,
+        issuer_did: &str,
+        issuer_key: &str,
+        bbs_secret: &str,
+        new_valid_until: &str,
+    ) -> Result<String, VadeEvanError> {
+        let mut credential = Credential::new(self)?;
+        credential
+            .refresh_credential(
+                old_credential,
+                issuer_did,
+                issuer_key,
+                bbs_secret,
+                new_valid_until,
+            )
+            .await
+            .map_err(|err| err.into())
+    }
+
     /// Runs a custom function, this allows to use `Vade`s API for custom calls, that do not belong
     /// to `Vade`s core functionality but may be required for a projects use cases.
     ///
+    /// Plugins decide based on `method` and `function` if they handle a call. The result of the
+    /// first plugin handling it is returned, an empty string if that plugin returned no value.
+    /// If no plugin handles the call, `VadeEvanError::NotHandled` is returned, errors returned by
+    /// a plugin are returned as `VadeEvanError::PluginFailed`.
+    ///
     /// # Arguments
     ///
     /// * `method` - method to call a function for (e.g. "did:example")
@@ -1070,7 +2615,9 @@ impl VadeEvan {
         get_first_result(
             self.vade
                 .run_custom_function(method, function, options, payload)
-                .await?,
+                .await,
+            "run_custom_function",
+            method,
         )
     }
 
@@ -1109,10 +2656,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_create_credential_definition(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_create_credential_definition",
+            method,
         )
     }
 
@@ -1149,10 +2699,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_create_credential_offer(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_create_credential_offer",
+            method,
         )
     }
 
@@ -1190,10 +2743,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_create_credential_proposal(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_create_credential_proposal",
+            method,
         )
     }
 
@@ -1231,10 +2787,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_create_credential_schema(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_create_credential_schema",
+            method,
         )
     }
 
@@ -1273,10 +2832,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_create_revocation_registry_definition(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_create_revocation_registry_definition",
+            method,
         )
     }
 
@@ -1314,10 +2876,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_update_revocation_registry(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_update_revocation_registry",
+            method,
         )
     }
 
@@ -1355,10 +2920,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_issue_credential(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_issue_credential",
+            method,
         )
     }
 
@@ -1395,10 +2963,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_finish_credential(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_finish_credential",
+            method,
         )
     }
 
@@ -1435,10 +3006,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_propose_proof(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_propose_proof",
+            method,
         )
     }
 
@@ -1476,10 +3050,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_present_proof(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_present_proof",
+            method,
         )
     }
 
@@ -1516,10 +3093,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_request_credential(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_request_credential",
+            method,
         )
     }
 
@@ -1556,10 +3136,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_request_proof(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_request_proof",
+            method,
         )
     }
 
@@ -1597,10 +3180,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_revoke_credential(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_revoke_credential",
+            method,
         )
     }
 
@@ -1637,10 +3223,13 @@ impl VadeEvan {
         options: &str,
         payload: &str,
     ) -> Result<String, VadeEvanError> {
+        self.check_vc_zkp_options(options)?;
         get_first_result(
             self.vade
                 .vc_zkp_verify_proof(method, options, payload)
-                .await?,
+                .await,
+            "vc_zkp_verify_proof",
+            method,
         )
     }
 
@@ -1753,6 +3342,191 @@ impl VadeEvan {
 
         did_helper.update(did, operation, update_key, payload).await
     }
+
+    /// Creates a long-form DID, that embeds the given initial DID document. Long-form DIDs can be
+    /// used before anchoring a DID, as `did_resolve` resolves them locally without network access.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial_document` - JSON string with initial DID document to embed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    /// if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///     use anyhow::Result;
+    ///     use vade_evan::{VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///     async fn example() -> Result<()> {
+    ///         let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///         let did = vade_evan.helper_did_create_long_form(r#"{ "service": [] }"#)?;
+    ///         let did_document = vade_evan.did_resolve(&did).await?;
+    ///         println!("long-form did {} resolves to: {}", did, did_document);
+    ///         Ok(())
+    ///        }
+    ///    } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "did-sidetree")]
+    pub fn helper_did_create_long_form(
+        &mut self,
+        initial_document: &str,
+    ) -> Result<String, VadeEvanError> {
+        let did_helper = Did::new(self)?;
+        did_helper.create_long_form(initial_document)
+    }
+
+    /// Executes multiple operations in order. Operation inputs given as `None` are taken from the
+    /// result of the previous operation, e.g. a credential offer created in one step can be used
+    /// for a credential request in the next one.
+    ///
+    /// Execution stops at the first failing operation unless `continue_on_error` is set. Inputs
+    /// that should be taken from a failed operation result in `VadeEvanError::MissingBatchInput`.
+    ///
+    /// # Arguments
+    ///
+    /// * `operations` - operations to execute
+    /// * `continue_on_error` - true to execute remaining operations after an operation failed
+    ///
+    /// # Returns
+    /// * `Vec<Result<String, VadeEvanError>>` - results of executed operations in order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// cfg_if::cfg_if! {
+    ///     if #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))] {
+    ///         use anyhow::Result;
+    ///         use vade_evan::{BatchOperation, VadeEvan, VadeEvanConfig, DEFAULT_TARGET, DEFAULT_SIGNER};
+    ///
+    ///         async fn example() -> Result<()> {
+    ///             let mut vade_evan = VadeEvan::new(VadeEvanConfig { target: DEFAULT_TARGET, signer: DEFAULT_SIGNER })?;
+    ///             let results = vade_evan
+    ///                 .batch(
+    ///                     vec![
+    ///                         BatchOperation::RunCustomFunction {
+    ///                             method: "did:example".to_string(),
+    ///                             function: "get did".to_string(),
+    ///                             options: "".to_string(),
+    ///                             payload: Some("".to_string()),
+    ///                         },
+    ///                         BatchOperation::DidResolve { did: None },
+    ///                     ],
+    ///                     false,
+    ///                 )
+    ///                 .await;
+    ///             println!("batch results: {:?}", results);
+    ///             Ok(())
+    ///         }
+    ///     } else {
+    ///         // currently no example for target-c-sdk and c-lib/target-java-lib
+    ///     }
+    /// }
+    /// ```
+    pub async fn batch(
+        &mut self,
+        operations: Vec<BatchOperation>,
+        continue_on_error: bool,
+    ) -> Vec<Result<String, VadeEvanError>> {
+        let mut results: Vec<Result<String, VadeEvanError>> = Vec::with_capacity(operations.len());
+        for (index, operation) in operations.into_iter().enumerate() {
+            let previous_output = results.last().and_then(|result| result.as_ref().ok().cloned());
+            let result = self.run_batch_operation(index, operation, previous_output).await;
+            let failed = result.is_err();
+            results.push(result);
+            if failed && !continue_on_error {
+                break;
+            }
+        }
+
+        results
+    }
+
+    async fn run_batch_operation(
+        &mut self,
+        index: usize,
+        operation: BatchOperation,
+        previous_output: Option<String>,
+    ) -> Result<String, VadeEvanError> {
+        let get_input = |value: Option<String>| {
+            value
+                .or_else(|| previous_output.clone())
+                .ok_or(VadeEvanError::MissingBatchInput { index })
+        };
+
+        match operation {
+            BatchOperation::DidResolve { did } => self.did_resolve(&get_input(did)?).await,
+            BatchOperation::RunCustomFunction {
+                method,
+                function,
+                options,
+                payload,
+            } => {
+                self.run_custom_function(&method, &function, &options, &get_input(payload)?).await
+            }
+            #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+            BatchOperation::CreateCredentialOffer {
+                schema_did,
+                use_valid_until,
+                issuer_did,
+                is_credential_status_included,
+                required_reveal_statements,
+                validate_issuer,
+            } => {
+                self.helper_create_credential_offer_with_options(
+                    &schema_did,
+                    use_valid_until,
+                    &issuer_did,
+                    is_credential_status_included,
+                    &required_reveal_statements,
+                    &CredentialOfferOptions {
+                        validate_issuer,
+                        ..Default::default()
+                    },
+                )
+                .await
+            }
+            #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+            BatchOperation::CreateCredentialRequest {
+                issuer_public_key,
+                bbs_secret,
+                credential_values,
+                credential_offer,
+                credential_schema_did,
+            } => {
+                self.helper_create_credential_request(
+                    &issuer_public_key,
+                    &bbs_secret,
+                    &credential_values,
+                    &get_input(credential_offer)?,
+                    &credential_schema_did,
+                )
+                .await
+            }
+            #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+            BatchOperation::CreateProofRequestFromProposal { proof_proposal } => {
+                self.helper_create_proof_request_from_proposal(&get_input(proof_proposal)?).await
+            }
+            #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+            BatchOperation::VerifyCredential {
+                credential,
+                master_secret,
+                expected_subject,
+            } => {
+                let credential = get_input(credential)?;
+                self.helper_verify_credential_with_subject(
+                    &credential,
+                    &master_secret,
+                    expected_subject.as_deref(),
+                )
+                .await?;
+                Ok(credential)
+            }
+        }
+    }
 }
 
 #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))]
@@ -1760,6 +3534,154 @@ impl VadeEvan {
 mod tests {
     use crate::{VadeEvan, VadeEvanConfig};
 
+    struct EchoPlugin {}
+
+    #[async_trait::async_trait(?Send)]
+    impl vade::VadePlugin for EchoPlugin {
+        async fn run_custom_function(
+            &mut self,
+            method: &str,
+            function: &str,
+            _options: &str,
+            payload: &str,
+        ) -> Result<vade::VadePluginResultValue<Option<String>>, Box<dyn std::error::Error>> {
+            if method == "did:echo" && function == "fail" {
+                return Err(Box::from("echo plugin failed"));
+            }
+            if method != "did:echo" || function != "echo" {
+                return Ok(vade::VadePluginResultValue::Ignored);
+            }
+            Ok(vade::VadePluginResultValue::Success(Some(payload.to_string())))
+        }
+    }
+
+    fn get_vade_evan_with_echo_plugin() -> VadeEvan {
+        let mut vade = vade::Vade::new();
+        vade.register_plugin(Box::new(EchoPlugin {}));
+        VadeEvan {
+            vade,
+            #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+            issuer_key_cache: crate::api::IssuerKeyCache::new(0),
+            #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+            verification_cache: crate::api::VerificationCache::new(0),
+            check_vc_zkp_options: false,
+            #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+            check_signed_id: false,
+            #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+            check_schema_properties: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn can_run_custom_function_of_plugin() -> anyhow::Result<()> {
+        let mut vade_evan = get_vade_evan_with_echo_plugin();
+
+        let result = vade_evan
+            .run_custom_function("did:echo", "echo", "{}", r#"{"hello":"world"}"#)
+            .await?;
+
+        assert_eq!(result, r#"{"hello":"world"}"#);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn run_custom_function_fails_if_not_handled() {
+        let mut vade_evan = get_vade_evan_with_echo_plugin();
+
+        let result = vade_evan
+            .run_custom_function("did:echo", "unknown function", "{}", "{}")
+            .await;
+
+        match result {
+            Ok(_) => assert!(false, "got result for function no plugin handles"),
+            Err(crate::VadeEvanError::NotHandled {
+                function,
+                did_or_method,
+            }) => {
+                assert_eq!(function, "run_custom_function");
+                assert_eq!(did_or_method, "did:echo");
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+    }
+
+    #[tokio::test]
+    async fn run_custom_function_returns_plugin_errors_with_function_and_method() {
+        let mut vade_evan = get_vade_evan_with_echo_plugin();
+
+        let result = vade_evan
+            .run_custom_function("did:echo", "fail", "{}", "{}")
+            .await;
+
+        match result {
+            Ok(_) => assert!(false, "got result for failing function"),
+            Err(crate::VadeEvanError::PluginFailed {
+                function,
+                did_or_method,
+                source_message,
+            }) => {
+                assert_eq!(function, "run_custom_function");
+                assert_eq!(did_or_method, "did:echo");
+                assert_eq!(source_message, "echo plugin failed");
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+    }
+
+    #[tokio::test]
+    async fn batch_passes_result_to_next_operation() {
+        let mut vade_evan = get_vade_evan_with_echo_plugin();
+        let echo = |payload: Option<&str>| crate::BatchOperation::RunCustomFunction {
+            method: "did:echo".to_string(),
+            function: "echo".to_string(),
+            options: "{}".to_string(),
+            payload: payload.map(|value| value.to_string()),
+        };
+
+        let results = vade_evan
+            .batch(vec![echo(Some(r#"{"step":1}"#)), echo(None)], false)
+            .await;
+
+        assert_eq!(results.len(), 2);
+        for result in results {
+            match result {
+                Ok(output) => assert_eq!(output, r#"{"step":1}"#),
+                Err(err) => assert!(false, "got unexpected error: {}", err),
+            };
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_stops_at_first_error_unless_told_to_continue() {
+        let mut vade_evan = get_vade_evan_with_echo_plugin();
+        let operations = vec![
+            crate::BatchOperation::RunCustomFunction {
+                method: "did:echo".to_string(),
+                function: "unknown function".to_string(),
+                options: "{}".to_string(),
+                payload: Some("{}".to_string()),
+            },
+            crate::BatchOperation::RunCustomFunction {
+                method: "did:echo".to_string(),
+                function: "echo".to_string(),
+                options: "{}".to_string(),
+                payload: None,
+            },
+        ];
+
+        let results = vade_evan.batch(operations.clone(), false).await;
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(crate::VadeEvanError::NotHandled { .. })));
+
+        let results = vade_evan.batch(operations, true).await;
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            results[1],
+            Err(crate::VadeEvanError::MissingBatchInput { index: 1 })
+        ));
+    }
+
     #[test]
     fn can_be_created() {
         let vade_evan = VadeEvan::new(VadeEvanConfig {
@@ -1769,4 +3691,606 @@ mod tests {
 
         assert!(vade_evan.is_ok());
     }
+
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    struct CountingResolverPlugin {
+        resolve_count: std::rc::Rc<std::cell::Cell<usize>>,
+    }
+
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    #[async_trait::async_trait(?Send)]
+    impl vade::VadePlugin for CountingResolverPlugin {
+        async fn did_resolve(
+            &mut self,
+            did: &str,
+        ) -> Result<vade::VadePluginResultValue<Option<String>>, Box<dyn std::error::Error>> {
+            self.resolve_count.set(self.resolve_count.get() + 1);
+            let did_document = serde_json::json!({
+                "didDocument": {
+                    "id": did,
+                    "verificationMethod": [{
+                        "id": "#bbs-key-1",
+                        "type": "Bls12381G2Key2020",
+                        "publicKeyJwk": { "kty": "EC", "crv": "BLS12381_G2", "x": "bbs-key" }
+                    }],
+                    "assertionMethod": ["#bbs-key-1"]
+                }
+            });
+            Ok(vade::VadePluginResultValue::Success(Some(did_document.to_string())))
+        }
+    }
+
+    /// Never finishes resolving `pending_did`, ignores all other DIDs.
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    struct PendingResolverPlugin {
+        pending_did: &'static str,
+    }
+
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    #[async_trait::async_trait(?Send)]
+    impl vade::VadePlugin for PendingResolverPlugin {
+        async fn did_resolve(
+            &mut self,
+            did: &str,
+        ) -> Result<vade::VadePluginResultValue<Option<String>>, Box<dyn std::error::Error>> {
+            if did == self.pending_did {
+                futures::future::pending::<()>().await;
+            }
+            Ok(vade::VadePluginResultValue::Ignored)
+        }
+    }
+
+    /// Resolves a single DID to a given result, ignores all other DIDs.
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    struct SingleDidResolverPlugin {
+        did: &'static str,
+        did_result: String,
+    }
+
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    #[async_trait::async_trait(?Send)]
+    impl vade::VadePlugin for SingleDidResolverPlugin {
+        async fn did_resolve(
+            &mut self,
+            did: &str,
+        ) -> Result<vade::VadePluginResultValue<Option<String>>, Box<dyn std::error::Error>> {
+            if did != self.did {
+                return Ok(vade::VadePluginResultValue::Ignored);
+            }
+            Ok(vade::VadePluginResultValue::Success(Some(self.did_result.clone())))
+        }
+    }
+
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    fn get_vade_evan_with_counting_resolver(
+        resolve_count: std::rc::Rc<std::cell::Cell<usize>>,
+    ) -> VadeEvan {
+        let mut vade = vade::Vade::new();
+        vade.register_plugin(Box::new(CountingResolverPlugin { resolve_count }));
+        VadeEvan {
+            vade,
+            issuer_key_cache: crate::api::IssuerKeyCache::new(10),
+            verification_cache: crate::api::VerificationCache::new(0),
+            check_vc_zkp_options: false,
+            check_signed_id: false,
+            check_schema_properties: false,
+        }
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    async fn second_issuer_key_lookup_hits_cache() -> anyhow::Result<()> {
+        use std::{cell::Cell, rc::Rc};
+
+        const ISSUER_DID: &str = "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA";
+
+        let resolve_count = Rc::new(Cell::new(0));
+        let mut vade_evan = get_vade_evan_with_counting_resolver(resolve_count.clone());
+        let mut credential = crate::helpers::Credential::new(&mut vade_evan)?;
+
+        for _ in 0..2 {
+            let public_key = credential
+                .get_issuer_public_key_for_purpose(ISSUER_DID, "#bbs-key-1", "assertionMethod")
+                .await?;
+            assert_eq!(public_key, "bbs-key");
+        }
+        assert_eq!(resolve_count.get(), 1);
+
+        credential.get_issuer_public_key(ISSUER_DID, "#bbs-key-1").await?;
+        assert_eq!(resolve_count.get(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    async fn issuer_key_lookups_hit_warmed_cache() -> anyhow::Result<()> {
+        use std::{cell::Cell, rc::Rc};
+
+        const ISSUER_DIDS: [&str; 2] = [
+            "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "did:evan:EiBrPL8Yif5NWHOzbKvyh1PX1wKVlWvIa6nTG1v8PXytvg",
+        ];
+
+        let resolve_count = Rc::new(Cell::new(0));
+        let mut vade_evan = get_vade_evan_with_counting_resolver(resolve_count.clone());
+        vade_evan.helper_warm_cache(&ISSUER_DIDS).await?;
+        assert_eq!(resolve_count.get(), 2);
+
+        let mut credential = crate::helpers::Credential::new(&mut vade_evan)?;
+
+        for issuer_did in ISSUER_DIDS {
+            let public_key = credential
+                .get_issuer_public_key_for_purpose(issuer_did, "#bbs-key-1", "assertionMethod")
+                .await?;
+            assert_eq!(public_key, "bbs-key");
+            credential.get_issuer_public_key(issuer_did, "#bbs-key-1").await?;
+        }
+        assert_eq!(resolve_count.get(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    async fn warm_cache_reports_timed_out_dids() -> anyhow::Result<()> {
+        use crate::helpers::CredentialError;
+        use std::{cell::Cell, rc::Rc, time::Duration};
+
+        const ISSUER_DIDS: [&str; 2] = [
+            "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "did:evan:EiBrPL8Yif5NWHOzbKvyh1PX1wKVlWvIa6nTG1v8PXytvg",
+        ];
+
+        let resolve_count = Rc::new(Cell::new(0));
+        let mut vade_evan = get_vade_evan_with_counting_resolver(resolve_count.clone());
+        vade_evan.vade.register_plugin(Box::new(PendingResolverPlugin {
+            pending_did: ISSUER_DIDS[0],
+        }));
+
+        match vade_evan
+            .helper_warm_cache_with_timeout(&ISSUER_DIDS, Duration::from_millis(50))
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for pending DID resolution"),
+            Err(crate::VadeEvanError::CredentialError(CredentialError::CacheWarmUpFailed(
+                message,
+            ))) => {
+                assert!(message.contains(&format!("{}: resolution timed out", ISSUER_DIDS[0])));
+                assert!(!message.contains(ISSUER_DIDS[1]));
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        // keys of DIDs resolved in time have been cached nonetheless
+        let resolved_count = resolve_count.get();
+        let mut credential = crate::helpers::Credential::new(&mut vade_evan)?;
+        credential.get_issuer_public_key(ISSUER_DIDS[1], "#bbs-key-1").await?;
+        assert_eq!(resolve_count.get(), resolved_count);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    async fn resolves_sidetree_long_form_dids_with_plugins() -> anyhow::Result<()> {
+        use std::{cell::Cell, rc::Rc};
+
+        let initial_state = serde_json::json!({
+            "suffixData": {
+                "deltaHash": "EiCfDWRnYlcD9EGA3d_5Z1AHu-iYqMbJ9nfiqdz5S8VDbg",
+                "recoveryCommitment": "EiBfOZdMtU6OBw8Pk879QtZ-2J-9FbbjSZyoaA_bqD4zhA"
+            },
+            "delta": {
+                "patches": [{ "action": "replace", "document": { "publicKeys": [] } }],
+                "updateCommitment": "EiDKIkwqO69IPG3pOlHkdb86nYt0aNxSHZu2r-bhEznjdA"
+            }
+        });
+        let did = format!(
+            "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA:{}",
+            base64::encode_config(initial_state.to_string(), base64::URL_SAFE_NO_PAD),
+        );
+        let resolve_count = Rc::new(Cell::new(0));
+        let mut vade_evan = get_vade_evan_with_counting_resolver(resolve_count.clone());
+
+        let resolved = vade_evan.did_resolve(&did).await?;
+
+        let resolved: serde_json::Value = serde_json::from_str(&resolved)?;
+        assert_eq!(resolved["didDocument"]["id"], did.as_str());
+        assert_eq!(resolve_count.get(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    async fn structurally_invalid_credential_fails_without_resolving() -> anyhow::Result<()> {
+        use std::{cell::Cell, rc::Rc};
+
+        let credential_str = serde_json::json!({
+            "@context": ["https://www.w3.org/2018/credentials/v1", "https://schema.org/"],
+            "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
+            "type": ["VerifiableCredential"],
+            "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "issuanceDate": "yesterday",
+            "credentialSchema": {
+                "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
+                "type": "EvanVCSchema"
+            },
+            "credentialSubject": { "data": { "bio": "biography" } },
+            "proof": {
+                "type": "BbsBlsSignature2020",
+                "created": "2023-02-01T14:08:17.000Z",
+                "signature": "Zm9vYmFy",
+                "proofPurpose": "assertionMethod",
+                "verificationMethod":
+                    "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA#bbs-key-1",
+                "credentialMessageCount": 13,
+                "requiredRevealStatements": []
+            }
+        })
+        .to_string();
+
+        let resolve_count = Rc::new(Cell::new(0));
+        let mut vade_evan = get_vade_evan_with_counting_resolver(resolve_count.clone());
+        let mut credential = crate::helpers::Credential::new(&mut vade_evan)?;
+
+        match credential.verify_credential(&credential_str, "", None).await {
+            Ok(_) => assert!(false, "got no error for invalid issuance date"),
+            Err(crate::helpers::CredentialError::InvalidTimestamp { field }) => {
+                assert_eq!(field, "issuanceDate");
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+        assert_eq!(resolve_count.get(), 0);
+
+        Ok(())
+    }
+
+    struct StaticResolverPlugin {
+        did_document: serde_json::Value,
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl vade::VadePlugin for StaticResolverPlugin {
+        async fn did_resolve(
+            &mut self,
+            _did: &str,
+        ) -> Result<vade::VadePluginResultValue<Option<String>>, Box<dyn std::error::Error>> {
+            let did_result = serde_json::json!({ "didDocument": self.did_document });
+            Ok(vade::VadePluginResultValue::Success(Some(did_result.to_string())))
+        }
+    }
+
+    /// Gets a VadeEvan instance, that resolves all DIDs to a raw RevocationList2020 list.
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    fn get_vade_evan_with_revocation_list(decoded_list: &[u8]) -> VadeEvan {
+        let revocation_list = serde_json::json!({
+            "credentialSubject": {
+                "type": "RevocationList2020",
+                "encoding": "raw",
+                "encodedList": base64::encode_config(decoded_list, base64::URL_SAFE),
+            },
+        });
+        let mut vade = vade::Vade::new();
+        vade.register_plugin(Box::new(StaticResolverPlugin {
+            did_document: revocation_list,
+        }));
+        VadeEvan {
+            vade,
+            issuer_key_cache: crate::api::IssuerKeyCache::new(0),
+            verification_cache: crate::api::VerificationCache::new(0),
+            check_vc_zkp_options: false,
+            check_signed_id: false,
+            check_schema_properties: false,
+        }
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    async fn finds_next_free_index_of_revocation_list() -> anyhow::Result<()> {
+        use crate::helpers::CredentialError;
+
+        const REVOCATION_LIST_DID: &str = "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA";
+
+        // indices 0 to 10 and 12 are flagged
+        let mut vade_evan = get_vade_evan_with_revocation_list(&[0b1111_1111, 0b0001_0111, 0]);
+        assert_eq!(
+            vade_evan
+                .helper_next_free_revocation_index(REVOCATION_LIST_DID)
+                .await?,
+            11,
+        );
+
+        let mut vade_evan = get_vade_evan_with_revocation_list(&[u8::MAX; 16]);
+        match vade_evan
+            .helper_next_free_revocation_index(REVOCATION_LIST_DID)
+            .await
+        {
+            Ok(_) => assert!(false, "got free index in full revocation list"),
+            Err(crate::VadeEvanError::CredentialError(CredentialError::RevocationListFull(_))) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    async fn lists_revoked_indices_of_resolved_revocation_list() -> anyhow::Result<()> {
+        const REVOCATION_LIST_DID: &str = "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA";
+
+        // indices 0, 3 and 9 are flagged
+        let mut vade_evan = get_vade_evan_with_revocation_list(&[0b0000_1001, 0b0000_0010]);
+        assert_eq!(
+            vade_evan.helper_list_revoked_indices(REVOCATION_LIST_DID).await?,
+            vec![0, 3, 9],
+        );
+
+        let mut vade_evan = get_vade_evan_with_revocation_list(&[0, 0]);
+        assert!(vade_evan
+            .helper_list_revoked_indices(REVOCATION_LIST_DID)
+            .await?
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_empty_did_documents() -> anyhow::Result<()> {
+        const DID: &str = "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA";
+
+        for did_document in [serde_json::Value::Null, serde_json::json!({})] {
+            let mut vade = vade::Vade::new();
+            vade.register_plugin(Box::new(StaticResolverPlugin { did_document }));
+            let mut vade_evan = VadeEvan {
+                vade,
+                #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+                issuer_key_cache: crate::api::IssuerKeyCache::new(0),
+                #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+                verification_cache: crate::api::VerificationCache::new(0),
+                check_vc_zkp_options: false,
+                #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+                check_signed_id: false,
+                #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+                check_schema_properties: false,
+            };
+
+            match vade_evan.did_resolve_typed(DID).await {
+                Ok(_) => assert!(false, "got no error for empty DID document"),
+                Err(crate::VadeEvanError::EmptyDidDocument { did }) => assert_eq!(did, DID),
+                Err(err) => assert!(false, "got unexpected error: {}", err),
+            };
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn can_resolve_did_into_typed_document() -> anyhow::Result<()> {
+        let issuer_did = "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA";
+        let mut vade_evan = VadeEvan::new(VadeEvanConfig {
+            target: "test",
+            signer: "remote|http://127.0.0.1:7070/key/sign",
+        })?;
+
+        let did_document = vade_evan.did_resolve_typed(issuer_did).await?;
+
+        assert_eq!(did_document.id, issuer_did);
+        let verification_methods = did_document
+            .verification_method
+            .ok_or_else(|| anyhow::anyhow!("no verification methods in DID document"))?;
+        assert!(verification_methods
+            .iter()
+            .any(|method| method.id.ends_with("#bbs-key-1")));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn vc_zkp_functions_reject_malformed_options() -> anyhow::Result<()> {
+        use crate::VadeEvanError;
+
+        let mut vade_evan = VadeEvan::new(VadeEvanConfig {
+            target: "test",
+            signer: "remote|http://127.0.0.1:7070/key/sign",
+        })?;
+        vade_evan.set_vc_zkp_options_check(true);
+
+        for options in ["", "not json", "{}", r#"{ "type": 1 }"#] {
+            match vade_evan
+                .vc_zkp_create_credential_offer("did:evan", options, "{}")
+                .await
+            {
+                Err(VadeEvanError::InvalidOptions { .. }) => {}
+                Err(err) => assert!(false, "got unexpected error for {}: {}", options, err),
+                Ok(_) => assert!(false, "options {} should have been rejected", options),
+            };
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn vc_zkp_functions_accept_options_with_type() -> anyhow::Result<()> {
+        use crate::VadeEvanError;
+
+        let mut vade_evan = VadeEvan::new(VadeEvanConfig {
+            target: "test",
+            signer: "remote|http://127.0.0.1:7070/key/sign",
+        })?;
+        vade_evan.set_vc_zkp_options_check(true);
+
+        let result = vade_evan
+            .vc_zkp_create_credential_offer("did:evan", r#"{ "type": "bbs" }"#, "{}")
+            .await;
+
+        assert!(!matches!(result, Err(VadeEvanError::InvalidOptions { .. })));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn vc_zkp_functions_do_not_check_options_by_default() -> anyhow::Result<()> {
+        use crate::VadeEvanError;
+
+        let mut vade_evan = VadeEvan::new(VadeEvanConfig {
+            target: "test",
+            signer: "remote|http://127.0.0.1:7070/key/sign",
+        })?;
+
+        let result = vade_evan
+            .vc_zkp_create_credential_offer("did:evan", "{}", "{}")
+            .await;
+
+        assert!(!matches!(result, Err(VadeEvanError::InvalidOptions { .. })));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    async fn helper_verify_credential_reports_message_count_mismatch() -> anyhow::Result<()> {
+        use crate::{helpers::CredentialError, VadeEvanError};
+
+        let credential = r###"{
+            "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
+            "type": [
+                "VerifiableCredential"
+            ],
+            "proof": {
+                "type": "BbsBlsSignature2020",
+                "created": "2023-02-01T14:08:17.000Z",
+                "signature": "kvSyi40dnZ5S3/mSxbSUQGKLpyMXDQNLCPtwDGM9GsnNNKF7MtaFHXIbvXaVXku0EY/n2uNMQ2bmK2P0KEmzgbjRHtzUOWVdfAnXnVRy8/UHHIyJR471X6benfZk8KG0qVqy+w67z9g628xRkFGA5Q==",
+                "proofPurpose": "assertionMethod",
+                "verificationMethod": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA#bbs-key-1",
+                "credentialMessageCount": 12,
+                "requiredRevealStatements": []
+            },
+            "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "@context": [
+                "https://www.w3.org/2018/credentials/v1",
+                "https://schema.org/",
+                "https://w3id.org/vc-revocation-list-2020/v1"
+            ],
+            "issuanceDate": "2023-02-01T14:08:09.849Z",
+            "credentialSchema": {
+                "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
+                "type": "EvanVCSchema"
+            },
+            "credentialStatus": {
+                "id": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA#4",
+                "type": "RevocationList2020Status",
+                "revocationListIndex": "4",
+                "revocationListCredential": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA"
+            },
+            "credentialSubject": {
+                "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+                "data": {
+                    "bio": "biography"
+                }
+            }
+        }"###;
+        let mut vade_evan = VadeEvan::new(VadeEvanConfig {
+            target: "test",
+            signer: "remote|http://127.0.0.1:7070/key/sign",
+        })?;
+
+        match vade_evan
+            .helper_verify_credential(credential, "QyRmu33oIQFNW+dSI5wex3u858Ra7yx5O1tsxJgQvu8=")
+            .await
+        {
+            Err(VadeEvanError::CredentialError(CredentialError::MessageCountMismatch(
+                got,
+                expected,
+            ))) => {
+                assert_eq!(got, 12);
+                assert_eq!(expected, 13);
+            }
+            Ok(_) => assert!(false, "credential with wrong message count should not verify"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    async fn second_credential_verification_hits_cache() -> anyhow::Result<()> {
+        const REVOCATION_LIST_DID: &str = "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA";
+
+        let credential = r###"{
+            "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
+            "type": [
+                "VerifiableCredential"
+            ],
+            "proof": {
+                "type": "BbsBlsSignature2020",
+                "created": "2023-02-01T14:08:17.000Z",
+                "signature": "kvSyi40dnZ5S3/mSxbSUQGKLpyMXDQNLCPtwDGM9GsnNNKF7MtaFHXIbvXaVXku0EY/n2uNMQ2bmK2P0KEmzgbjRHtzUOWVdfAnXnVRy8/UHHIyJR471X6benfZk8KG0qVqy+w67z9g628xRkFGA5Q==",
+                "proofPurpose": "assertionMethod",
+                "verificationMethod": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA#bbs-key-1",
+                "credentialMessageCount": 13,
+                "requiredRevealStatements": []
+            },
+            "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "@context": [
+                "https://www.w3.org/2018/credentials/v1",
+                "https://schema.org/",
+                "https://w3id.org/vc-revocation-list-2020/v1"
+            ],
+            "issuanceDate": "2023-02-01T14:08:09.849Z",
+            "credentialSchema": {
+                "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
+                "type": "EvanVCSchema"
+            },
+            "credentialStatus": {
+                "id": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA#4",
+                "type": "RevocationList2020Status",
+                "revocationListIndex": "4",
+                "revocationListCredential": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA"
+            },
+            "credentialSubject": {
+                "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+                "data": {
+                    "bio": "biography"
+                }
+            }
+        }"###;
+        let master_secret = "QyRmu33oIQFNW+dSI5wex3u858Ra7yx5O1tsxJgQvu8=";
+        let mut vade_evan = VadeEvan::new(VadeEvanConfig {
+            target: crate::DEFAULT_TARGET,
+            signer: crate::DEFAULT_SIGNER,
+        })?;
+        vade_evan.set_verification_cache_ttl(60);
+
+        vade_evan.helper_verify_credential(credential, master_secret).await?;
+
+        // only the revocation list can be resolved anymore, as it is checked on cache hits
+        let revocation_list = vade_evan.did_resolve(REVOCATION_LIST_DID).await?;
+        vade_evan.vade = vade::Vade::new();
+        vade_evan.vade.register_plugin(Box::new(SingleDidResolverPlugin {
+            did: REVOCATION_LIST_DID,
+            did_result: revocation_list.clone(),
+        }));
+        vade_evan.helper_verify_credential(credential, master_secret).await?;
+
+        vade_evan.vade = vade::Vade::new();
+        assert!(vade_evan
+            .helper_verify_credential(credential, master_secret)
+            .await
+            .is_err());
+
+        vade_evan.vade.register_plugin(Box::new(SingleDidResolverPlugin {
+            did: REVOCATION_LIST_DID,
+            did_result: revocation_list,
+        }));
+        vade_evan.set_verification_cache_ttl(0);
+        assert!(vade_evan
+            .helper_verify_credential(credential, master_secret)
+            .await
+            .is_err());
+
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@
 use crate::helpers::CredentialError;
 #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
 use crate::helpers::PresentationError;
+use crate::helpers::ValidationError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -12,6 +13,27 @@ pub enum VadeEvanError {
     InternalError { source_message: String },
     #[error("vade call returned no results")]
     NoResults,
+    #[error("vade call {function} for '{did_or_method}' has not been handled by any plugin")]
+    NotHandled {
+        function: String,
+        did_or_method: String,
+    },
+    #[error("vade call {function} for '{did_or_method}' failed in plugin: {source_message}")]
+    PluginFailed {
+        function: String,
+        did_or_method: String,
+        source_message: String,
+    },
+    #[error("invalid options provided; {source_message}")]
+    InvalidOptions { source_message: String },
+    #[error(r#"invalid DID "{did}"; {source_message}"#)]
+    InvalidDid { did: String, source_message: String },
+    #[error(r#"DID "{did}" resolved to an empty DID document"#)]
+    EmptyDidDocument { did: String },
+    #[error("invalid DID document; {}", join_errors(.errors))]
+    InvalidDidDocument { errors: Vec<ValidationError> },
+    #[error("batch operation {index} requires the result of the previous operation")]
+    MissingBatchInput { index: usize },
     #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
     #[error(transparent)]
     CredentialError(#[from] CredentialError),
@@ -27,3 +49,11 @@ impl From<Box<dyn std::error::Error>> for VadeEvanError {
         }
     }
 }
+
+fn join_errors(errors: &[ValidationError]) -> String {
+    errors
+        .iter()
+        .map(|err| err.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
@@ -0,0 +1,162 @@
+/*
+  Copyright (c) 2018-present evan GmbH.
+
+  Licensed under the Apache License, Version 2.0 (the "License");
+  you may not use this file except in compliance with the License.
+  You may obtain a copy of the License at
+
+      http://www.apache.org/licenses/LICENSE-2.0
+
+  Unless required by applicable law or agreed to in writing, software
+  distributed under the License is distributed on an "AS IS" BASIS,
+  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+  See the License for the specific language governing permissions and
+  limitations under the License.
+*/
+
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, sync::Mutex};
+
+// upper bound for cached results, new results are not cached while the cache is full
+const VERIFICATION_CACHE_MAX_SIZE: usize = 1000;
+
+/// Cache for successful credential verifications. Only positive results are stored, so invalid
+/// credentials are always verified again.
+pub(crate) struct VerificationCache {
+    ttl_seconds: u64,
+    // expiry timestamps by cache key
+    entries: Mutex<HashMap<String, i64>>,
+}
+
+impl VerificationCache {
+    /// Creates a new cache, a `ttl_seconds` of 0 disables caching.
+    pub fn new(ttl_seconds: u64) -> Self {
+        Self {
+            ttl_seconds,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Updates the time results are kept in cache, clears cached results.
+    pub fn set_ttl(&mut self, ttl_seconds: u64) {
+        self.ttl_seconds = ttl_seconds;
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.ttl_seconds > 0
+    }
+
+    /// Checks if a verification with given key has succeeded within the configured TTL.
+    pub fn contains(&self, key: &str) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+        let mut entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(_) => return false,
+        };
+        match entries.get(key) {
+            Some(expires_at) if *expires_at > Utc::now().timestamp() => true,
+            Some(_) => {
+                entries.remove(key);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Stores a successful verification for the configured TTL.
+    pub fn insert(&self, key: &str) {
+        if !self.is_enabled() {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            let now = Utc::now().timestamp();
+            if entries.len() >= VERIFICATION_CACHE_MAX_SIZE {
+                entries.retain(|_, expires_at| *expires_at > now);
+            }
+            if entries.len() < VERIFICATION_CACHE_MAX_SIZE {
+                let ttl_seconds = i64::try_from(self.ttl_seconds).unwrap_or(i64::MAX);
+                entries.insert(key.to_owned(), now.saturating_add(ttl_seconds));
+            }
+        }
+    }
+}
+
+/// Optional checks a credential has been verified with. Verifications with different checks are
+/// cached separately, so a result of a less strict verification does not answer a stricter one.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct VerificationFlags {
+    pub check_signed_id: bool,
+    pub check_schema_properties: bool,
+}
+
+/// Gets the cache key for a credential verification, made up of a hash of the credential, the
+/// master secret it has been verified with and the optional checks it has been verified with.
+/// The master secret itself is not stored.
+pub(crate) fn get_verification_cache_key(
+    credential: &str,
+    master_secret: &str,
+    flags: VerificationFlags,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(Sha256::digest(credential.as_bytes()));
+    hasher.update(Sha256::digest(master_secret.as_bytes()));
+    hasher.update([
+        u8::from(flags.check_signed_id),
+        u8::from(flags.check_schema_properties),
+    ]);
+
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_verification_cache_key, VerificationCache, VerificationFlags};
+
+    #[test]
+    fn returns_cached_result_for_same_key_only() {
+        let flags = VerificationFlags::default();
+        let cache = VerificationCache::new(60);
+        let key = get_verification_cache_key("{}", "secret", flags);
+        cache.insert(&key);
+
+        assert!(cache.contains(&key));
+        assert!(!cache.contains(&get_verification_cache_key("{}", "other secret", flags)));
+        assert!(!cache.contains(&get_verification_cache_key("{ }", "secret", flags)));
+    }
+
+    #[test]
+    fn does_not_answer_stricter_verifications_with_cached_result() {
+        let cache = VerificationCache::new(60);
+        cache.insert(&get_verification_cache_key("{}", "secret", VerificationFlags::default()));
+
+        for flags in [
+            VerificationFlags {
+                check_signed_id: true,
+                check_schema_properties: false,
+            },
+            VerificationFlags {
+                check_signed_id: false,
+                check_schema_properties: true,
+            },
+        ] {
+            assert!(!cache.contains(&get_verification_cache_key("{}", "secret", flags)));
+        }
+    }
+
+    #[test]
+    fn does_not_cache_results_with_ttl_zero() {
+        let mut cache = VerificationCache::new(60);
+        let key = get_verification_cache_key("{}", "secret", VerificationFlags::default());
+        cache.insert(&key);
+        cache.set_ttl(0);
+        cache.insert(&key);
+
+        assert!(!cache.contains(&key));
+    }
+}
@@ -35,6 +35,13 @@ pub struct Response {
     pub response: Option<String>,
 }
 
+/// Length-delimited buffer returned by [`execute_vade_bytes`].
+#[repr(C)]
+pub struct ByteBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
 macro_rules! execute_vade_function {
     ($func_name:ident, $did_or_method:expr, $config:expr, #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))] $request_id:expr, #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))] $callback:expr) => {
         async {
@@ -183,6 +190,143 @@ fn get_config_values(
     Ok(vec)
 }
 
+/// Gets the minimum and maximum number of arguments a function accepts, trailing arguments that
+/// are optional may be omitted by the caller.
+fn get_argument_count_range(func: &str) -> Option<(usize, usize)> {
+    match func {
+        "get_version_info" => Some((0, 0)),
+        "did_resolve" | "didcomm_receive" | "didcomm_send" => Some((1, 1)),
+        "did_create"
+        | "did_update"
+        | "vc_zkp_create_credential_offer"
+        | "vc_zkp_create_credential_proposal"
+        | "vc_zkp_create_credential_schema"
+        | "vc_zkp_create_revocation_registry_definition"
+        | "vc_zkp_update_revocation_registry"
+        | "vc_zkp_issue_credential"
+        | "vc_zkp_finish_credential"
+        | "vc_zkp_present_proof"
+        | "vc_zkp_request_credential"
+        | "vc_zkp_propose_proof"
+        | "vc_zkp_request_proof"
+        | "vc_zkp_revoke_credential"
+        | "vc_zkp_verify_proof"
+        | "helper_verify_presentation" => Some((2, 2)),
+        "helper_verify_credential" => Some((2, 3)),
+        "helper_did_create" => Some((0, 5)),
+        "helper_did_update" => Some((4, 4)),
+        "helper_create_credential_offer" => Some((5, 6)),
+        "helper_create_credential_request" => Some((5, 5)),
+        "helper_revoke_credential"
+        | "helper_verify_credential_any_key"
+        | "run_custom_function" => Some((3, 3)),
+        "helper_create_self_issued_credential" => Some((9, 9)),
+        "helper_create_proof_proposal" | "helper_create_proof_request" => Some((1, 2)),
+        "helper_create_presentation" => Some((5, 6)),
+        _ => None,
+    }
+}
+
+/// Ensures that a function has been called with a number of arguments it can handle, so missing
+/// arguments are not silently replaced with empty strings.
+fn check_argument_count(func: &str, num_of_args: usize) -> Result<(), String> {
+    let (min, max) = match get_argument_count_range(func) {
+        Some(range) => range,
+        None => return Ok(()),
+    };
+    if num_of_args < min || num_of_args > max {
+        let expected = match min == max {
+            true => min.to_string(),
+            false => format!("{} to {}", min, max),
+        };
+        return Err(format!(
+            "function {} expects {} arguments, got {}",
+            func, expected, num_of_args
+        ));
+    }
+
+    Ok(())
+}
+
+fn get_serialized_response(result: Result<String, String>) -> String {
+    let response = match result.as_ref() {
+        Ok(value) => Response {
+            response: Some(value.to_string()),
+            error: None,
+        },
+        Err(e) => Response {
+            response: None,
+            error: Some(e.to_string()),
+        },
+    };
+
+    let serialized_response = serde_json::to_string(&response);
+    match serialized_response {
+        Ok(string_result) => string_result,
+        _ => "{\"error\": \"Failed to serialize response\"}".to_string(),
+    }
+}
+
+fn get_response_string(result: Result<String, String>) -> *const c_char {
+    CString::new(get_serialized_response(result))
+        .expect("CString::new failed to convert response")
+        .into_raw()
+}
+
+fn get_response_bytes(result: Result<String, String>) -> ByteBuffer {
+    let response = get_serialized_response(result).into_bytes().into_boxed_slice();
+    let len = response.len();
+
+    ByteBuffer {
+        data: Box::into_raw(response) as *mut u8,
+        len,
+    }
+}
+
+/// Converts a C string to a Rust string. Fails for invalid UTF-8, unless built with the
+/// `c-lib-lossy-utf8` feature, which replaces invalid sequences with replacement characters.
+fn get_c_string(value: *const c_char, name: &str) -> Result<String, String> {
+    let c_str = unsafe { CStr::from_ptr(value) };
+    if cfg!(feature = "c-lib-lossy-utf8") {
+        return Ok(c_str.to_string_lossy().into_owned());
+    }
+
+    c_str
+        .to_str()
+        .map(|value| value.to_owned())
+        .map_err(|err| format!("{} is not valid UTF-8; {}", name, err))
+}
+
+/// Converts length-delimited arguments to strings. Arguments may contain NUL bytes. Arguments
+/// that are not valid UTF-8, like raw key material, are passed to vade functions base64 encoded,
+/// as they expect binary data as base64 strings.
+fn get_byte_arguments(
+    arguments: *const *const u8,
+    argument_lengths: *const usize,
+    num_of_args: usize,
+) -> Vec<String> {
+    if num_of_args == 0 {
+        return Vec::new();
+    }
+    let args_array: &[*const u8] = unsafe { slice::from_raw_parts(arguments, num_of_args) };
+    let lengths_array: &[usize] = unsafe { slice::from_raw_parts(argument_lengths, num_of_args) };
+
+    args_array
+        .iter()
+        .zip(lengths_array.iter())
+        .map(|(&argument, &length)| {
+            if argument.is_null() {
+                return String::new();
+            }
+            let bytes = unsafe { slice::from_raw_parts(argument, length) };
+            match std::str::from_utf8(bytes) {
+                Ok(argument) => argument.to_owned(),
+                Err(_) => base64::encode(bytes),
+            }
+        })
+        .collect()
+}
+
 /// Executes a vade call.
 ///
 /// About the `config` argument setup used here:
@@ -200,20 +344,150 @@ pub extern "C" fn execute_vade(
     #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
     request_function_callback: ResolveHttpRequest,
 ) -> *const c_char {
-    let func = unsafe { CStr::from_ptr(func_name).to_string_lossy().into_owned() };
     let args_array: &[*const c_char] =
         unsafe { slice::from_raw_parts(arguments, num_of_args as usize) };
-    // convert each element to a Rust string
-    let arguments_vec: Vec<_> = args_array
+    let result = get_c_string(func_name, "function name").and_then(|func| {
+        // convert each element to a Rust string
+        let arguments_vec = args_array
+            .iter()
+            .enumerate()
+            .map(|(index, &v)| {
+                if !v.is_null() {
+                    get_c_string(v, &format!("argument {}", index))
+                } else {
+                    Ok(String::new())
+                }
+            })
+            .collect::<Result<Vec<String>, String>>()?;
+
+        run_vade_function(
+            &func,
+            arguments_vec,
+            options,
+            config,
+            #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
+            request_function_callback,
+        )
+    });
+
+    get_response_string(result)
+}
+
+/// Executes a vade call with length-delimited arguments and response, so payloads containing
+/// NUL bytes are passed without being truncated. Arguments that are not valid UTF-8 are passed
+/// base64 encoded. The function name has to be valid UTF-8 in any case.
+///
+/// The returned buffer contains the same JSON response as returned by [`execute_vade`] and has
+/// to be released with [`free_byte_buffer`].
+///
+/// About the `config` argument setup used here:
+///
+/// - if built for C and having sdk target enabled: type is `*const c_void`
+/// - for any other build: type is `*const c_char`
+#[no_mangle]
+pub extern "C" fn execute_vade_bytes(
+    func_name: *const c_char,
+    arguments: *const *const u8,
+    argument_lengths: *const usize,
+    num_of_args: usize,
+    options: *const c_char,
+    #[cfg(all(feature = "c-lib", not(feature = "target-c-sdk")))] config: *const c_char,
+    #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))] config: *const c_void,
+    #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
+    request_function_callback: ResolveHttpRequest,
+) -> ByteBuffer {
+    let result = unsafe { CStr::from_ptr(func_name) }
+        .to_str()
+        .map_err(|err| format!("function name is not valid UTF-8; {}", err))
+        .and_then(|func| {
+            let arguments_vec = get_byte_arguments(arguments, argument_lengths, num_of_args);
+            run_vade_function(
+                func,
+                arguments_vec,
+                options,
+                config,
+                #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
+                request_function_callback,
+            )
+        });
+
+    get_response_bytes(result)
+}
+
+/// Releases a buffer returned by [`execute_vade_bytes`].
+#[no_mangle]
+pub extern "C" fn free_byte_buffer(buffer: ByteBuffer) {
+    if buffer.data.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Box::from_raw(slice::from_raw_parts_mut(buffer.data, buffer.len)));
+    }
+}
+
+/// Gets a feature that is required for a function but disabled in this build, so calls to such
+/// functions can be told apart from calls to unknown functions.
+fn get_disabled_feature(func: &str) -> Option<&'static str> {
+    let required_features: &[(&str, bool)] = match func {
+        "did_resolve" => &[("did-read", cfg!(feature = "did-read"))],
+        "did_create" | "did_update" => &[("did-write", cfg!(feature = "did-write"))],
+        "helper_did_create" | "helper_did_update" => {
+            &[("did-sidetree", cfg!(feature = "did-sidetree"))]
+        }
+        "didcomm_receive" | "didcomm_send" => &[("didcomm", cfg!(feature = "didcomm"))],
+        "vc_zkp_issue_credential" | "vc_zkp_verify_proof" => {
+            &[("vc-zkp", cfg!(feature = "vc-zkp"))]
+        }
+        "vc_zkp_create_credential_offer"
+        | "vc_zkp_create_credential_proposal"
+        | "vc_zkp_create_credential_schema"
+        | "vc_zkp_create_revocation_registry_definition"
+        | "vc_zkp_update_revocation_registry"
+        | "vc_zkp_finish_credential"
+        | "vc_zkp_present_proof"
+        | "vc_zkp_request_credential"
+        | "vc_zkp_propose_proof"
+        | "vc_zkp_request_proof"
+        | "vc_zkp_revoke_credential"
+        | "run_custom_function" => &[("vc-zkp-bbs", cfg!(feature = "vc-zkp-bbs"))],
+        "helper_create_credential_offer"
+        | "helper_create_credential_request"
+        | "helper_verify_credential"
+        | "helper_verify_credential_any_key"
+        | "helper_revoke_credential"
+        | "helper_create_self_issued_credential"
+        | "helper_create_proof_proposal"
+        | "helper_create_proof_request"
+        | "helper_create_presentation"
+        | "helper_verify_presentation" => &[
+            ("vc-zkp-bbs", cfg!(feature = "vc-zkp-bbs")),
+            ("did-sidetree", cfg!(feature = "did-sidetree")),
+        ],
+        _ => &[],
+    };
+
+    required_features
         .iter()
-        .map(|&v| {
-            if !v.is_null() {
-                unsafe { CStr::from_ptr(v).to_string_lossy().into_owned() }
-            } else {
-                String::new()
-            }
-        })
-        .collect();
+        .find(|(_, is_enabled)| !is_enabled)
+        .map(|(feature, _)| *feature)
+}
+
+fn run_vade_function(
+    func: &str,
+    arguments_vec: Vec<String>,
+    options: *const c_char,
+    #[cfg(all(feature = "c-lib", not(feature = "target-c-sdk")))] config: *const c_char,
+    #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))] config: *const c_void,
+    #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
+    request_function_callback: ResolveHttpRequest,
+) -> Result<String, String> {
+    if let Some(feature) = get_disabled_feature(func) {
+        return Err(format!(
+            "function '{}' is not available: feature '{}' disabled",
+            func, feature
+        ));
+    }
+    check_argument_count(func, arguments_vec.len())?;
 
     let mut str_options = String::new();
 
@@ -223,12 +497,12 @@ pub extern "C" fn execute_vade(
     let str_config = String::new();
 
     if !options.is_null() {
-        str_options = unsafe { CStr::from_ptr(options).to_string_lossy().into_owned() };
+        str_options = get_c_string(options, "options")?;
     }
 
     #[cfg(not(feature = "target-c-sdk"))]
     if !config.is_null() {
-        str_config = unsafe { CStr::from_ptr(config).to_string_lossy().into_owned() };
+        str_config = get_c_string(config, "config")?;
     }
 
     #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
@@ -242,7 +516,7 @@ pub extern "C" fn execute_vade(
         .build()
         .expect("Failed to create runtime");
 
-    let result = match func.as_str() {
+    match func {
         #[cfg(feature = "did-read")]
         "did_resolve" => runtime.block_on({
             execute_vade_function!(
@@ -555,13 +829,21 @@ pub extern "C" fn execute_vade(
                     Some(value) => value.to_lowercase() == "true",
                     None => false,
                 };
+                let validate_issuer = match arguments_vec.get(5) {
+                    Some(value) => value.to_lowercase() == "true",
+                    None => false,
+                };
                 vade_evan
-                    .helper_create_credential_offer(
+                    .helper_create_credential_offer_with_options(
                         arguments_vec.get(0).unwrap_or_else(|| &no_args),
                         use_valid_until,
                         arguments_vec.get(2).unwrap_or_else(|| &no_args),
                         is_credential_status_included,
                         arguments_vec.get(4).unwrap_or_else(|| &no_args),
+                        &crate::helpers::CredentialOfferOptions {
+                            validate_issuer,
+                            ..Default::default()
+                        },
                     )
                     .await
                     .map_err(stringify_vade_evan_error)
@@ -602,15 +884,37 @@ pub extern "C" fn execute_vade(
                 )
                 .map_err(stringify_generic_error)?;
                 vade_evan
-                    .helper_verify_credential(
+                    .helper_verify_credential_with_subject(
                         arguments_vec.get(0).unwrap_or_else(|| &no_args),
                         arguments_vec.get(1).unwrap_or_else(|| &no_args),
+                        arguments_vec.get(2).map(|v| v.as_str()),
                     )
                     .await
                     .map_err(stringify_vade_evan_error)?;
                 Ok("".to_string())
             }
         }),
+        #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+        "helper_verify_credential_any_key" => runtime.block_on({
+            async {
+                let mut vade_evan = get_vade_evan(
+                    Some(&str_config),
+                    #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
+                    ptr_request_list,
+                    #[cfg(all(feature = "c-lib", feature = "target-c-sdk"))]
+                    request_function_callback,
+                )
+                .map_err(stringify_generic_error)?;
+                vade_evan
+                    .helper_verify_credential_any_key(
+                        arguments_vec.get(0).unwrap_or_else(|| &no_args),
+                        arguments_vec.get(1).unwrap_or_else(|| &no_args),
+                        arguments_vec.get(2).unwrap_or_else(|| &no_args),
+                    )
+                    .await
+                    .map_err(stringify_vade_evan_error)
+            }
+        }),
 
         #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
         "helper_revoke_credential" => runtime.block_on({
@@ -781,26 +1085,228 @@ pub extern "C" fn execute_vade(
         .map_err(stringify_generic_error)
         .map(|vade_evan| vade_evan.get_version_info()),
         _ => Err("Function not supported by Vade".to_string()),
-    };
+    }
+}
 
-    let response = match result.as_ref() {
-        Ok(value) => Response {
-            response: Some(value.to_string()),
-            error: None,
-        },
-        Err(e) => Response {
-            response: None,
-            error: Some(e.to_string()),
-        },
+#[cfg(test)]
+#[cfg(not(feature = "target-c-sdk"))]
+mod tests {
+    use super::{
+        execute_vade,
+        execute_vade_bytes,
+        free_byte_buffer,
+        get_argument_count_range,
+        get_byte_arguments,
+        get_disabled_feature,
     };
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+    use std::{ptr, slice};
 
-    let serialized_response = serde_json::to_string(&response);
-    let string_response = match serialized_response {
-        Ok(string_result) => string_result,
-        _ => "{\"error\": \"Failed to serialize response\"}".to_string(),
-    };
+    fn call_execute_vade(func_name: &str, arguments: &[&str]) -> serde_json::Value {
+        let func_name = CString::new(func_name).expect("could not create function name");
+        let arguments: Vec<CString> = arguments
+            .iter()
+            .map(|argument| CString::new(*argument).expect("could not create argument"))
+            .collect();
+        let argument_pointers: Vec<*const c_char> =
+            arguments.iter().map(|argument| argument.as_ptr()).collect();
 
-    return CString::new(string_response)
-        .expect("CString::new failed to convert response")
-        .into_raw();
+        let response = execute_vade(
+            func_name.as_ptr(),
+            argument_pointers.as_ptr(),
+            argument_pointers.len(),
+            ptr::null(),
+            ptr::null(),
+        );
+        let response = unsafe { CStr::from_ptr(response).to_string_lossy().into_owned() };
+
+        serde_json::from_str(&response).expect("could not parse response")
+    }
+
+    fn call_execute_vade_bytes(func_name: &str, arguments: &[&[u8]]) -> serde_json::Value {
+        let func_name = CString::new(func_name).expect("could not create function name");
+        let argument_pointers: Vec<*const u8> =
+            arguments.iter().map(|argument| argument.as_ptr()).collect();
+        let argument_lengths: Vec<usize> =
+            arguments.iter().map(|argument| argument.len()).collect();
+
+        let buffer = execute_vade_bytes(
+            func_name.as_ptr(),
+            argument_pointers.as_ptr(),
+            argument_lengths.as_ptr(),
+            arguments.len(),
+            ptr::null(),
+            ptr::null(),
+        );
+        let response = unsafe { slice::from_raw_parts(buffer.data, buffer.len) }.to_vec();
+        free_byte_buffer(buffer);
+
+        serde_json::from_slice(&response).expect("could not parse response")
+    }
+
+    #[test]
+    fn rejects_call_with_too_few_arguments() {
+        let response = call_execute_vade("did_resolve", &[]);
+
+        assert_eq!(response["error"], "function did_resolve expects 1 arguments, got 0");
+    }
+
+    #[test]
+    fn rejects_call_with_too_many_arguments() {
+        let response = call_execute_vade("get_version_info", &["unexpected"]);
+
+        assert_eq!(response["error"], "function get_version_info expects 0 arguments, got 1");
+    }
+
+    #[test]
+    fn rejects_call_with_too_few_arguments_for_optional_arguments() {
+        let response = call_execute_vade("helper_create_credential_offer", &["did:evan:123"]);
+
+        assert_eq!(
+            response["error"],
+            "function helper_create_credential_offer expects 5 to 6 arguments, got 1"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+    fn rejects_call_without_candidate_method_ids() {
+        let response = call_execute_vade("helper_verify_credential_any_key", &["{}", "secret"]);
+
+        assert_eq!(
+            response["error"],
+            "function helper_verify_credential_any_key expects 3 arguments, got 2"
+        );
+    }
+
+    #[test]
+    fn accepts_call_with_expected_number_of_arguments() {
+        let response = call_execute_vade("get_version_info", &[]);
+
+        assert!(response["error"].is_null());
+        assert!(response["response"].is_string());
+    }
+
+    #[test]
+    fn does_not_restrict_arguments_of_unknown_functions() {
+        assert_eq!(get_argument_count_range("unknown_function"), None);
+
+        let response = call_execute_vade("unknown_function", &["a", "b", "c"]);
+
+        assert_eq!(response["error"], "Function not supported by Vade");
+    }
+
+    #[test]
+    fn does_not_report_disabled_features_for_available_functions() {
+        assert_eq!(get_disabled_feature("get_version_info"), None);
+        assert_eq!(get_disabled_feature("unknown_function"), None);
+        #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+        assert_eq!(get_disabled_feature("helper_verify_credential"), None);
+        #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+        assert_eq!(get_disabled_feature("helper_verify_credential_any_key"), None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "didcomm"))]
+    fn reports_function_of_disabled_feature() {
+        let response = call_execute_vade("didcomm_send", &["{}"]);
+
+        assert_eq!(
+            response["error"],
+            "function 'didcomm_send' is not available: feature 'didcomm' disabled"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "vc-zkp-bbs"))]
+    fn reports_helper_function_of_disabled_feature() {
+        let response = call_execute_vade("helper_verify_presentation", &["{}", "{}"]);
+
+        assert_eq!(
+            response["error"],
+            "function 'helper_verify_presentation' is not available: feature 'vc-zkp-bbs' disabled"
+        );
+    }
+
+    #[test]
+    fn keeps_nul_bytes_in_byte_arguments() {
+        let argument: &[u8] = b"{\"payload\":\"a\0b\"}";
+        let arguments = [argument.as_ptr()];
+        let lengths = [argument.len()];
+
+        let arguments_vec = get_byte_arguments(arguments.as_ptr(), lengths.as_ptr(), 1);
+
+        assert_eq!(arguments_vec, vec!["{\"payload\":\"a\0b\"}".to_string()]);
+    }
+
+    #[test]
+    fn can_execute_vade_with_byte_arguments() {
+        let response = call_execute_vade_bytes("get_version_info", &[]);
+
+        assert!(response["error"].is_null());
+        assert!(response["response"].is_string());
+    }
+
+    #[test]
+    #[cfg(not(feature = "c-lib-lossy-utf8"))]
+    fn rejects_arguments_with_invalid_utf8() {
+        let func_name = CString::new("get_version_info").expect("could not create function name");
+        let arguments = [
+            CString::new("valid").expect("could not create argument"),
+            CString::new(vec![b'a', 0xff]).expect("could not create argument"),
+        ];
+        let argument_pointers: Vec<*const c_char> =
+            arguments.iter().map(|argument| argument.as_ptr()).collect();
+
+        let response = execute_vade(
+            func_name.as_ptr(),
+            argument_pointers.as_ptr(),
+            argument_pointers.len(),
+            ptr::null(),
+            ptr::null(),
+        );
+        let response = unsafe { CStr::from_ptr(response).to_string_lossy().into_owned() };
+        let response: serde_json::Value =
+            serde_json::from_str(&response).expect("could not parse response");
+
+        assert_eq!(
+            response["error"].as_str(),
+            Some("argument 1 is not valid UTF-8; invalid utf-8 sequence of 1 bytes from index 1"),
+        );
+    }
+
+    #[test]
+    fn encodes_byte_arguments_with_invalid_utf8_as_base64() {
+        let argument: &[u8] = &[0xff, 0x00];
+        let arguments = [argument.as_ptr()];
+        let lengths = [argument.len()];
+
+        let arguments_vec = get_byte_arguments(arguments.as_ptr(), lengths.as_ptr(), 1);
+
+        assert_eq!(arguments_vec, vec!["/wA=".to_string()]);
+    }
+
+    #[test]
+    fn rejects_byte_call_with_invalid_utf8_in_function_name() {
+        let func_name = CString::new(vec![b'a', 0xff]).expect("could not create function name");
+
+        let buffer = execute_vade_bytes(
+            func_name.as_ptr(),
+            ptr::null(),
+            ptr::null(),
+            0,
+            ptr::null(),
+            ptr::null(),
+        );
+        let response = unsafe { slice::from_raw_parts(buffer.data, buffer.len) }.to_vec();
+        free_byte_buffer(buffer);
+        let response: serde_json::Value =
+            serde_json::from_slice(&response).expect("could not parse response");
+
+        assert!(response["error"]
+            .as_str()
+            .expect("error should be a string")
+            .starts_with("function name is not valid UTF-8"));
+    }
 }
@@ -1,22 +1,52 @@
-use crate::api::VadeEvan;
+use crate::api::{
+    get_verification_cache_key,
+    parse_did_document,
+    VadeEvan,
+    VerificationFlags,
+};
 use crate::helpers::datatypes::EVAN_METHOD;
-use std::{io::Read, panic};
+use std::{
+    collections::BTreeMap,
+    io::Read,
+    time::Duration,
+};
 
-use super::datatypes::{DidDocumentResult, IdentityDidDocument};
-use super::shared::{check_for_optional_empty_params, convert_to_nquads, is_did, SharedError};
+use super::datatypes::{IdentityDidDocument, VERIFICATION_RELATIONSHIPS};
+use super::shared::{
+    check_for_optional_empty_params,
+    convert_to_nquads,
+    convert_to_nquads_strict,
+    is_did,
+    SharedError,
+};
+use base64::{encode_config, URL_SAFE_NO_PAD};
 use bbs::{
-    prelude::{DeterministicPublicKey, PublicKey},
+    prelude::{
+        DeterministicPublicKey,
+        PoKOfSignatureProof,
+        ProofNonce,
+        ProofRequest,
+        PublicKey,
+        SignatureProof,
+        Verifier,
+    },
     signature::Signature,
     HashElem,
     SignatureMessage,
 };
+use chrono::{DateTime, SecondsFormat, Utc};
 use flate2::read::GzDecoder;
-use serde::de::DeserializeOwned;
-use serde_json::{value::Value, Map};
+use futures::future::{select, Either};
+use futures_timer::Delay;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, value::Value, Map};
 use thiserror::Error;
 use vade_evan_bbs::{
+    recover_address_and_data,
     BbsCredential,
+    BbsCredentialOffer,
     BbsCredentialRequest,
+    BbsProof,
     CredentialDraftOptions,
     CredentialSchema,
     CredentialStatus,
@@ -28,13 +58,15 @@ use vade_evan_bbs::{
     RevocationListCredential,
     RevokeCredentialPayload,
     UnfinishedBbsCredential,
+    UnsignedBbsCredential,
 };
+use vade_signer::{LocalSigner, Signer};
 
 #[derive(Error, Debug)]
 pub enum CredentialError {
     #[error("internal VadeEvan call failed; {0}")]
     VadeEvanError(String),
-    #[error("invalid did document")]
+    #[error("invalid did document, {0}")]
     InvalidDidDocument(String),
     #[error("pubkey for verification method not found, {0}")]
     InvalidVerificationMethod(String),
@@ -56,24 +88,108 @@ pub enum CredentialError {
     RevocationListInvalid(String),
     #[error("revocation index invalid; {0}")]
     RevocationIndexInvalid(String),
+    #[error("revocation list full; {0}")]
+    RevocationListFull(String),
+    #[error("unsupported revocation list encoding; {detail}")]
+    UnsupportedRevocationEncoding { detail: String },
     #[error("credential has been revoked")]
     CredentialRevoked,
+    #[error("no matching issuer key; {0}")]
+    NoMatchingIssuerKey(String),
+    #[error(r#"verification method "{0}" is not authorized for proof purpose "{1}""#)]
+    UnauthorizedVerificationMethod(String, String),
+    #[error(r#"DID "{did}" does not resolve to a credential schema"#)]
+    NotASchema { did: String },
+    #[error(r#"credential id "{0}" is not covered by its signature"#)]
+    UnsignedCredentialId(String),
+    #[error(r#"property "{0}" is not defined in credential schema"#)]
+    UnexpectedProperty(String),
+    #[error("credential has no proof")]
+    MissingProof,
+    #[error(r#"unsupported proof type "{0}""#)]
+    UnsupportedProofType(String),
+    #[error(r#"proof type "{0}" hides attributes and can only be verified with its proof request"#)]
+    DerivedProofOutsidePresentation(String),
+    #[error("invalid JWT verifiable presentation; {0}")]
+    InvalidJwtPresentation(String),
+    #[error("invalid issuer; {0}")]
+    InvalidIssuer(String),
+    #[error("invalid credential offer; {0}")]
+    InvalidCredentialOffer(String),
+    #[error("credential @context does not match its content; {0}")]
+    ContextMismatch(String),
+    #[error("credential subject does not match expected subject; {0}")]
+    SubjectMismatch(String),
+    #[error(r#"invalid timestamp in "{field}", expected RFC3339 date-time"#)]
+    InvalidTimestamp { field: String },
     #[error("wrong number of messages in credential, got {0} but proof was created for {1}")]
     MessageCountMismatch(usize, usize),
     #[error(r#"value "{0}" given for "{1} is not a DID""#)]
     NotADid(String, String),
+    #[error(r#"attribute "{0}" not found in credential"#)]
+    UnknownAttribute(String),
+    #[error("could not cache keys of all issuers; {0}")]
+    CacheWarmUpFailed(String),
 }
 
 // Master secret is always incorporated, without being mentioned in the credential schema
 const ADDITIONAL_HIDDEN_MESSAGES_COUNT: usize = 1;
 const TYPE_OPTIONS: &str = r#"{ "type": "bbs" }"#;
+const REDACTED_VALUE: &str = "***";
+const REDACTED_SIGNATURE_PREFIX_LENGTH: usize = 8;
+// size of a compressed G2 point
+const BBS_PUBLIC_KEY_SIZE: usize = 96;
+const PROOF_TYPE_BBS: &str = "BbsBlsSignature2020";
+const PROOF_TYPE_BBS_DERIVED: &str = "BbsBlsSignatureProof2020";
+const JWT_HEADER: &str = r#"{"typ":"JWT","alg":"ES256K-R"}"#;
+const CONTEXT_CREDENTIALS_V1: &str = "https://www.w3.org/2018/credentials/v1";
+const CONTEXT_REVOCATION_LIST_2020: &str = "https://w3id.org/vc-revocation-list-2020/v1";
+const STATUS_LIST_TYPE_REVOCATION_LIST_2020: &str = "RevocationList2020";
+const STATUS_LIST_TYPE_STATUS_LIST_2021: &str = "StatusList2021";
+const SCHEMA_ORG_PREFIX: &str = "http://schema.org/";
+const STATUS_LIST_ENCODING_GZIP: &str = "gzip";
+const STATUS_LIST_ENCODING_RAW: &str = "raw";
+const SCHEMA_TYPE: &str = "EvanVCSchema";
+const SCHEMA_REQUIRED_FIELDS: [&str; 3] = ["id", "name", "properties"];
+// signatures consist of a G1 point and two field elements, which may be given with the point in
+// compressed or uncompressed form
+const SIGNATURE_COMPRESSED_SIZE: usize = 48 + 2 * 32;
+const SIGNATURE_UNCOMPRESSED_SIZE: usize = 96 + 2 * 32;
+const DEFAULT_WARM_CACHE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Decodes a base64 encoded value, accepting both the standard and the URL-safe alphabet.
+/// Returns the error of decoding with the standard alphabet if neither of them succeeds.
+fn decode_base64(value: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    base64::decode(value).or_else(|err| {
+        base64::decode_config(value, base64::URL_SAFE)
+            .or_else(|_| base64::decode_config(value, base64::URL_SAFE_NO_PAD))
+            .map_err(|_| err)
+    })
+}
+
+/// Parses a BBS signature from bytes, detecting compressed and uncompressed form by its length.
+fn parse_signature(signature_bytes: &[u8]) -> Result<Signature, CredentialError> {
+    match signature_bytes.len() {
+        SIGNATURE_COMPRESSED_SIZE => Signature::from_bytes_compressed_form(signature_bytes),
+        SIGNATURE_UNCOMPRESSED_SIZE => Signature::from_bytes_uncompressed_form(signature_bytes),
+        length => {
+            return Err(CredentialError::BbsValidationError(format!(
+                "Error parsing signature, unexpected length {}",
+                length
+            )))
+        }
+    }
+    .map_err(|err| {
+        CredentialError::BbsValidationError(format!("Error parsing signature; {}", err))
+    })
+}
 
 fn get_public_key_generator(
     public_key: &str,
     message_count: usize,
 ) -> Result<PublicKey, CredentialError> {
     let public_key: DeterministicPublicKey =
-        DeterministicPublicKey::from(base64::decode(public_key)?.into_boxed_slice());
+        DeterministicPublicKey::from(decode_base64(public_key)?.into_boxed_slice());
     let public_key_generator = public_key.to_public_key(message_count).map_err(|e| {
         CredentialError::PublicKeyParsingError(format!(
             "public key invalid, generate public key generator; {}",
@@ -84,6 +200,52 @@ fn get_public_key_generator(
     Ok(public_key_generator)
 }
 
+/// Validates a base64 encoded bbs public key and checks that generators for `message_count`
+/// messages can be derived from it.
+fn validate_public_key(public_key: &str, message_count: usize) -> Result<(), CredentialError> {
+    let decoded_key = decode_base64(public_key)?;
+    if decoded_key.len() != BBS_PUBLIC_KEY_SIZE {
+        return Err(CredentialError::PublicKeyParsingError(format!(
+            "public key has {} bytes, expected {}",
+            decoded_key.len(),
+            BBS_PUBLIC_KEY_SIZE
+        )));
+    }
+    if message_count <= ADDITIONAL_HIDDEN_MESSAGES_COUNT {
+        return Err(CredentialError::PublicKeyParsingError(format!(
+            "message count {} does not cover master secret and at least one claim",
+            message_count
+        )));
+    }
+    get_public_key_generator(public_key, message_count)?;
+
+    Ok(())
+}
+
+/// Replaces claim values and truncates signatures of a credential or presentation in place.
+fn redact_credential(value: &mut Value) {
+    if let Some(data) = value
+        .get_mut("credentialSubject")
+        .and_then(|subject| subject.get_mut("data"))
+        .and_then(|data| data.as_object_mut())
+    {
+        data.values_mut().for_each(|claim| *claim = Value::from(REDACTED_VALUE));
+    }
+    if let Some(proof) = value.get_mut("proof").and_then(|proof| proof.as_object_mut()) {
+        for field in ["signature", "proof", "jws"] {
+            if let Some(Value::String(signature)) = proof.get_mut(field) {
+                *signature = format!(
+                    "{}...",
+                    signature
+                        .chars()
+                        .take(REDACTED_SIGNATURE_PREFIX_LENGTH)
+                        .collect::<String>()
+                );
+            }
+        }
+    }
+}
+
 /// Checks if input is a DID and returns a `CredentialError::NotADid` if not.
 ///
 /// # Arguments
@@ -103,19 +265,540 @@ pub fn fail_if_not_a_did(to_check: &str, name: &str) -> Result<(), CredentialErr
     Ok(())
 }
 
+/// Normalizes a DID URL referencing a part of a DID document, so that relative references
+/// (e.g. "#bbs-key-1") and absolute references (e.g. "did:evan:...#bbs-key-1") can be compared.
+/// Query parameters are ignored.
+///
+/// # Arguments
+/// * `document_id` - id of the DID document the reference is used in
+/// * `did_url` - absolute or relative DID URL
+fn to_absolute_did_url(document_id: &str, did_url: &str) -> String {
+    let (base, fragment) = match did_url.split_once('#') {
+        Some((base, fragment)) => (base, Some(fragment)),
+        None => (did_url, None),
+    };
+    let base = base.split_once('?').map_or(base, |(base, _)| base);
+    let base = if base.is_empty() { document_id } else { base };
+
+    match fragment {
+        Some(fragment) => format!("{}#{}", base, fragment),
+        None => base.to_string(),
+    }
+}
+
+/// Ensures that the `@context` of a credential starts with the credentials context and contains
+/// all contexts needed for the properties it uses, as canonicalization of the credential into
+/// nquads depends on them.
+fn fail_on_context_mismatch(credential_value: &Value) -> Result<(), CredentialError> {
+    let context: Vec<&str> = credential_value["@context"]
+        .as_array()
+        .ok_or_else(|| CredentialError::ContextMismatch("@context is not an array".to_string()))?
+        .iter()
+        .filter_map(|entry| entry.as_str())
+        .collect();
+
+    if context.first() != Some(&CONTEXT_CREDENTIALS_V1) {
+        return Err(CredentialError::ContextMismatch(format!(
+            "first entry has to be {}",
+            CONTEXT_CREDENTIALS_V1
+        )));
+    }
+    if matches!(credential_value.get("credentialStatus"), Some(status) if !status.is_null())
+        && !context.contains(&CONTEXT_REVOCATION_LIST_2020)
+    {
+        return Err(CredentialError::ContextMismatch(format!(
+            "credentialStatus requires context {}",
+            CONTEXT_REVOCATION_LIST_2020
+        )));
+    }
+
+    Ok(())
+}
+
+/// Ensures that `issuanceDate` and `validUntil` of a credential are valid RFC3339 timestamps, so
+/// a malformed expiry date is not mistaken for a credential without expiry.
+fn fail_on_invalid_timestamps(credential_value: &Value) -> Result<(), CredentialError> {
+    for field in ["issuanceDate", "validUntil"] {
+        let value = match credential_value.get(field) {
+            Some(Value::Null) | None => continue,
+            Some(value) => value,
+        };
+        let is_valid = value
+            .as_str()
+            .map(|timestamp| DateTime::parse_from_rfc3339(timestamp).is_ok())
+            .unwrap_or(false);
+        if !is_valid {
+            return Err(CredentialError::InvalidTimestamp {
+                field: field.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Splits a credential into one credential per proof, as `proof` may be a single object or an
+/// array of proofs, e.g. an issuer proof and a holder binding proof.
+fn split_proofs(credential_str: &str) -> Result<Vec<Value>, CredentialError> {
+    let mut credential_value: Value = serde_json::from_str(credential_str)?;
+    let proofs = match credential_value["proof"].take() {
+        Value::Array(proofs) => proofs,
+        Value::Null => vec![],
+        proof => vec![proof],
+    };
+    if proofs.is_empty() {
+        return Err(CredentialError::MissingProof);
+    }
+
+    Ok(proofs
+        .into_iter()
+        .map(|proof| {
+            let mut credential_with_proof = credential_value.clone();
+            credential_with_proof["proof"] = proof;
+            credential_with_proof
+        })
+        .collect())
+}
+
+/// Ensures that a credential only contains properties defined in its schema, if the schema does
+/// not allow additional properties.
+fn fail_on_unexpected_property(
+    schema: &Value,
+    credential_value: &Value,
+) -> Result<(), CredentialError> {
+    if schema["additionalProperties"].as_bool() != Some(false) {
+        return Ok(());
+    }
+    let data = match credential_value["credentialSubject"]["data"].as_object() {
+        Some(data) => data,
+        None => return Ok(()),
+    };
+    match data.keys().find(|key| schema["properties"].get(key.as_str()).is_none()) {
+        Some(property) => Err(CredentialError::UnexpectedProperty(property.to_string())),
+        None => Ok(()),
+    }
+}
+
+/// Ensures that the `credentialSubject.id` of a credential matches the expected subject, if one
+/// is given, so credentials can be bound to the holder presenting them.
+fn fail_on_subject_mismatch(
+    credential_subject: &CredentialSubject,
+    expected_subject: Option<&str>,
+) -> Result<(), CredentialError> {
+    let expected_subject = match expected_subject {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    match credential_subject.id.as_deref() {
+        Some(subject) if subject == expected_subject => Ok(()),
+        Some(subject) => Err(CredentialError::SubjectMismatch(format!(
+            r#"expected "{}" but got "{}""#,
+            expected_subject, subject
+        ))),
+        None => Err(CredentialError::SubjectMismatch(format!(
+            r#"expected "{}" but credential has no subject id"#,
+            expected_subject
+        ))),
+    }
+}
+
+fn get_credential_subject(credential_str: &str) -> Result<CredentialSubject, CredentialError> {
+    let mut credential_value: Value = serde_json::from_str(credential_str)?;
+
+    Ok(serde_json::from_value(credential_value["credentialSubject"].take())?)
+}
+
+/// Ensures that verification method ids in a DID document are unique, as lookups by id would
+/// ignore all but the first method with a duplicated id.
+fn fail_on_duplicate_verification_method_ids(
+    did_document: &IdentityDidDocument,
+) -> Result<(), CredentialError> {
+    let mut ids: Vec<String> = vec![];
+    for method in did_document.verification_method.iter().flatten() {
+        let id = to_absolute_did_url(&did_document.id, &method.id);
+        if ids.contains(&id) {
+            return Err(CredentialError::InvalidDidDocument(format!(
+                "duplicate verification method id {}",
+                id
+            )));
+        }
+        ids.push(id);
+    }
+
+    Ok(())
+}
+
+/// Ensures that a DID document contains at least one verification method, that is authorized
+/// for assertions and provides a public key, so its controller is able to sign credentials.
+fn fail_if_no_signing_key(did_document: &IdentityDidDocument) -> Result<(), CredentialError> {
+    let has_signing_key = did_document
+        .get_verification_relationship("assertionMethod")
+        .map(|relationship| {
+            relationship.iter().any(|entry| {
+                get_public_key_from_did_document(did_document.clone(), entry.id()).is_ok()
+            })
+        })
+        .unwrap_or(false);
+
+    if !has_signing_key {
+        return Err(CredentialError::InvalidIssuer(format!(
+            "no usable signing key found in DID document of {}",
+            did_document.id
+        )));
+    }
+
+    Ok(())
+}
+
+fn get_public_key_from_did_document(
+    did_document: IdentityDidDocument,
+    verification_method_id: &str,
+) -> Result<String, CredentialError> {
+    let verification_method_id = to_absolute_did_url(&did_document.id, verification_method_id);
+    let verification_methods = did_document
+        .verification_method
+        .ok_or("no verification method found")
+        .map_err(|err| CredentialError::PublicKeyParsingError(err.to_string()))?;
+    let public_key = verification_methods
+        .iter()
+        .find(|method| to_absolute_did_url(&did_document.id, &method.id) == verification_method_id)
+        .map(|method| method.public_key_jwk.x.to_string())
+        .ok_or_else(|| {
+            CredentialError::InvalidVerificationMethod(format!(
+                "no public key found for verification id {}",
+                &verification_method_id
+            ))
+        })?;
+
+    Ok(public_key)
+}
+
+/// Gets the public keys of a DID document's own verification methods as they would be looked up
+/// without cache: by id relative to the document, e.g. "#bbs-key-1", and once for each proof
+/// purpose the method is authorized for. Methods of other DIDs are skipped.
+///
+/// # Returns
+/// * `Vec<(String, Option<&str>, String)>` - relative method id, proof purpose and public key
+fn get_cacheable_issuer_keys(
+    did_document: &IdentityDidDocument,
+) -> Result<Vec<(String, Option<&'static str>, String)>, CredentialError> {
+    fail_on_duplicate_verification_method_ids(did_document)?;
+    let own_method_prefix = format!("{}#", did_document.id);
+    let mut keys = vec![];
+    for method in did_document.verification_method.iter().flatten() {
+        let absolute_method_id = to_absolute_did_url(&did_document.id, &method.id);
+        let method_id = match absolute_method_id.strip_prefix(&own_method_prefix) {
+            Some(fragment) => format!("#{}", fragment),
+            None => continue,
+        };
+        let public_key = get_public_key_from_did_document(did_document.clone(), &method_id)?;
+        keys.push((method_id.clone(), None, public_key.clone()));
+        for proof_purpose in VERIFICATION_RELATIONSHIPS {
+            if check_verification_method_purpose(did_document, &method_id, proof_purpose).is_ok() {
+                keys.push((method_id.clone(), Some(proof_purpose), public_key.clone()));
+            }
+        }
+    }
+
+    Ok(keys)
+}
+
+/// Gets the BBS message index of the first nquad stating given `schema.org` attribute, including
+/// the hidden messages preceding the credential's nquads.
+fn get_attribute_index(nquads: &[String], attribute_name: &str) -> Option<usize> {
+    let predicate = format!("<{}{}>", SCHEMA_ORG_PREFIX, attribute_name);
+
+    nquads
+        .iter()
+        .position(|nquad| nquad.split_whitespace().nth(1) == Some(predicate.as_str()))
+        .map(|index| index + ADDITIONAL_HIDDEN_MESSAGES_COUNT)
+}
+
+/// Converts a credential to nquads, without its proof.
+async fn get_nquads_without_proof(credential_str: &str) -> Result<Vec<String>, CredentialError> {
+    let mut parsed_credential: Map<String, Value> = serde_json::from_str(credential_str)?;
+    parsed_credential.remove("proof");
+    let credential_without_proof = serde_json::to_string(&parsed_credential)?;
+
+    Ok(convert_to_nquads(&credential_without_proof).await?)
+}
+
+/// Converts a credential without its proof to nquads and ensures, that the number of messages
+/// matches the message count given in the proof, including the master secret. If
+/// `check_signed_id` is set, the credential id has to be part of the signed messages as well.
+async fn get_verified_nquads(
+    credential: &BbsCredential,
+    credential_str: &str,
+    check_signed_id: bool,
+) -> Result<Vec<String>, CredentialError> {
+    get_checked_nquads(credential, credential_str, true, check_signed_id).await
+}
+
+/// Like [`get_verified_nquads`], but for credentials that may have been signed without a master
+/// secret, as indicated by `is_bound`.
+async fn get_checked_nquads(
+    credential: &BbsCredential,
+    credential_str: &str,
+    is_bound: bool,
+    check_signed_id: bool,
+) -> Result<Vec<String>, CredentialError> {
+    let did_doc_nquads = get_nquads_without_proof(credential_str).await?;
+    // check id first, as nquads of unsigned ids are dropped, which changes the message count
+    if check_signed_id && !is_id_signed(&did_doc_nquads, &credential.id) {
+        return Err(CredentialError::UnsignedCredentialId(credential.id.clone()));
+    }
+    fail_on_message_count_mismatch(&did_doc_nquads, &credential.proof, is_bound)?;
+
+    Ok(did_doc_nquads)
+}
+
+/// Checks whether a credential id is covered by the signature over the given nquads. Ids that are
+/// absolute IRIs, e.g. "uuid:..." as set when creating unsigned credentials, are the subject of
+/// the credential's nquads, so changing them invalidates the signature. Other ids are replaced
+/// with blank nodes during canonicalization and could be changed after issuance unnoticed.
+fn is_id_signed(nquads: &[String], id: &str) -> bool {
+    let subject = format!("<{}>", id);
+    nquads
+        .iter()
+        .any(|nquad| nquad.split_whitespace().next() == Some(subject.as_str()))
+}
+
+/// Ensures, that the number of nquads matches the message count given in the proof. Credentials
+/// bound to a master secret have it signed as an additional hidden message.
+fn fail_on_message_count_mismatch(
+    did_doc_nquads: &[String],
+    proof: &BbsProof,
+    is_bound: bool,
+) -> Result<(), CredentialError> {
+    let hidden_message_count = if is_bound {
+        ADDITIONAL_HIDDEN_MESSAGES_COUNT
+    } else {
+        0
+    };
+    let expected_count = did_doc_nquads.len() + hidden_message_count;
+    if proof.credential_message_count != expected_count {
+        return Err(CredentialError::MessageCountMismatch(
+            proof.credential_message_count,
+            expected_count,
+        ));
+    }
+
+    Ok(())
+}
+
+fn get_master_secret_message(master_secret: &str) -> Result<SignatureMessage, CredentialError> {
+    Ok(SignatureMessage::from(
+        decode_base64(master_secret)?.into_boxed_slice(),
+    ))
+}
+
+/// Gets the messages revealed by a derived proof by their index. All nquads of the credential
+/// have to be revealed, as the indices of hidden attributes are only known from the proof request
+/// the proof has been created for; only the master secret stays hidden.
+fn get_revealed_messages(
+    nquads: &[String],
+    proof: &DerivedBbsProof,
+) -> Result<BTreeMap<usize, SignatureMessage>, CredentialError> {
+    if proof.credential_message_count != nquads.len() + ADDITIONAL_HIDDEN_MESSAGES_COUNT {
+        return Err(CredentialError::DerivedProofOutsidePresentation(
+            PROOF_TYPE_BBS_DERIVED.to_string(),
+        ));
+    }
+
+    Ok(nquads
+        .iter()
+        .enumerate()
+        .map(|(index, nquad)| {
+            (
+                index + ADDITIONAL_HIDDEN_MESSAGES_COUNT,
+                SignatureMessage::hash(nquad),
+            )
+        })
+        .collect())
+}
+
+/// Verifies a derived proof for the given revealed messages, using the nonce stored in the proof
+/// to rebuild the proof challenge.
+fn verify_derived_proof(
+    proof: &DerivedBbsProof,
+    revealed_messages: BTreeMap<usize, SignatureMessage>,
+    issuer_public_key: &str,
+) -> Result<(), CredentialError> {
+    let public_key_generator =
+        get_public_key_generator(issuer_public_key, proof.credential_message_count)?;
+    let signature_proof =
+        PoKOfSignatureProof::from_bytes_compressed_form(&decode_base64(&proof.proof)?).map_err(
+            |err| CredentialError::BbsValidationError(format!("Error parsing proof; {}", err)),
+        )?;
+    let proof_request = ProofRequest {
+        revealed_messages: revealed_messages.keys().copied().collect(),
+        verification_key: public_key_generator,
+    };
+    let signature_proof = SignatureProof {
+        revealed_messages,
+        proof: signature_proof,
+    };
+    let nonce = ProofNonce::from(decode_base64(&proof.nonce)?.into_boxed_slice());
+
+    Verifier::verify_signature_pok(&proof_request, &signature_proof, &nonce)
+        .map(|_| ())
+        .map_err(|err| CredentialError::BbsValidationError(err.to_string()))
+}
+
+/// Checks if a verification method is referenced in the verification relationship of a DID
+/// document, that matches the given proof purpose (e.g. "assertionMethod").
+pub fn check_verification_method_purpose(
+    did_document: &IdentityDidDocument,
+    verification_method_id: &str,
+    proof_purpose: &str,
+) -> Result<(), CredentialError> {
+    let verification_method_id = to_absolute_did_url(&did_document.id, verification_method_id);
+    let is_authorized = did_document
+        .get_verification_relationship(proof_purpose)
+        .map(|relationship| {
+            relationship.iter().any(|entry| {
+                to_absolute_did_url(&did_document.id, entry.id()) == verification_method_id
+            })
+        })
+        .unwrap_or(false);
+
+    if !is_authorized {
+        return Err(CredentialError::UnauthorizedVerificationMethod(
+            verification_method_id,
+            proof_purpose.to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Decodes the bitstring of a RevocationList2020 or StatusList2021 credential according to the
+/// `type` and `encoding` declared in its subject.
+fn decode_revocation_list(revocation_list: &Value) -> Result<Vec<u8>, CredentialError> {
+    let list_type = get_status_list_type(revocation_list)?;
+    let encoded_list = revocation_list["credentialSubject"]["encodedList"]
+        .as_str()
+        .ok_or_else(|| {
+            CredentialError::RevocationListInvalid("encodedList is missing".to_string())
+        })?;
+    let encoding = get_list_encoding(
+        list_type,
+        revocation_list["credentialSubject"]["encoding"].as_str(),
+    )?;
+
+    decode_encoded_list(encoded_list, encoding)
+}
+
+/// Gets the `type` of a status list's subject, fails for types other than RevocationList2020
+/// and StatusList2021.
+fn get_status_list_type(status_list: &Value) -> Result<&str, CredentialError> {
+    match status_list["credentialSubject"]["type"].as_str() {
+        Some(list_type @ STATUS_LIST_TYPE_REVOCATION_LIST_2020)
+        | Some(list_type @ STATUS_LIST_TYPE_STATUS_LIST_2021) => Ok(list_type),
+        list_type => Err(CredentialError::RevocationListInvalid(format!(
+            r#"unsupported status list type "{}""#,
+            list_type.unwrap_or_default()
+        ))),
+    }
+}
+
+/// Gets the bit mask of `index` inside its byte. RevocationList2020 lists start at the least
+/// significant bit of each byte, StatusList2021 lists at the most significant one.
+fn get_status_list_mask(list_type: &str, index: usize) -> u8 {
+    match list_type {
+        STATUS_LIST_TYPE_STATUS_LIST_2021 => 0x80 >> (index % 8),
+        _ => 1 << (index % 8),
+    }
+}
+
+/// Encoding of a status list bitstring inside its base64 encoding.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ListEncoding {
+    Gzip,
+    Raw,
+}
+
+/// Gets the encoding of a status list bitstring from the list's metadata. RevocationList2020 and
+/// StatusList2021 lists are gzipped, unless their subject declares another `encoding`.
+fn get_list_encoding(
+    list_type: &str,
+    encoding: Option<&str>,
+) -> Result<ListEncoding, CredentialError> {
+    if list_type != STATUS_LIST_TYPE_REVOCATION_LIST_2020
+        && list_type != STATUS_LIST_TYPE_STATUS_LIST_2021
+    {
+        return Err(CredentialError::UnsupportedRevocationEncoding {
+            detail: format!(r#"unknown encoding of status list type "{}""#, list_type),
+        });
+    }
+
+    match encoding.unwrap_or(STATUS_LIST_ENCODING_GZIP) {
+        STATUS_LIST_ENCODING_GZIP => Ok(ListEncoding::Gzip),
+        STATUS_LIST_ENCODING_RAW => Ok(ListEncoding::Raw),
+        encoding => Err(CredentialError::UnsupportedRevocationEncoding {
+            detail: format!(r#"unknown status list encoding "{}""#, encoding),
+        }),
+    }
+}
+
+/// Decodes a base64 encoded status list bitstring, accepts URL safe and standard base64
+/// encoding. Lists are decompressed according to their `encoding`.
+fn decode_encoded_list(
+    encoded_list: &str,
+    encoding: ListEncoding,
+) -> Result<Vec<u8>, CredentialError> {
+    let list_bytes = base64::decode_config(encoded_list, base64::URL_SAFE)
+        .or_else(|_| base64::decode_config(encoded_list, base64::STANDARD))
+        .map_err(|err| CredentialError::UnsupportedRevocationEncoding {
+            detail: format!("encoded list is not base64 encoded, {}", err),
+        })?;
+    if encoding == ListEncoding::Raw {
+        return Ok(list_bytes);
+    }
+
+    let mut decoder = GzDecoder::new(&list_bytes[..]);
+    let mut decoded_list = Vec::new();
+    decoder.read_to_end(&mut decoded_list).map_err(|err| {
+        CredentialError::UnsupportedRevocationEncoding {
+            detail: format!("invalid gzip data, {}", err),
+        }
+    })?;
+
+    Ok(decoded_list)
+}
+
+/// Returns the lowest index not flagged in given decoded revocation list bitstring.
+fn get_first_unset_index(decoded_list: &[u8]) -> Option<u32> {
+    decoded_list
+        .iter()
+        .enumerate()
+        .find(|(_, byte)| **byte != u8::MAX)
+        .map(|(byte_index, byte)| byte_index as u32 * 8 + (!byte).trailing_zeros())
+}
+
+/// Gets the indices flagged in a RevocationList2020 or StatusList2021 credential.
+fn get_revoked_indices(status_list: &Value) -> Result<Vec<u32>, CredentialError> {
+    let list_type = get_status_list_type(status_list)?;
+    let decoded_list = decode_revocation_list(status_list)?;
+
+    let mut indices = Vec::new();
+    for (byte_index, byte) in decoded_list.iter().enumerate() {
+        for bit in 0..8 {
+            if byte & get_status_list_mask(list_type, bit) != 0 {
+                indices.push(byte_index as u32 * 8 + bit as u32);
+            }
+        }
+    }
+
+    Ok(indices)
+}
+
 pub fn is_revoked(
     credential_status: &CredentialStatus,
-    revocation_list: &RevocationListCredential,
+    revocation_list: &Value,
 ) -> Result<bool, CredentialError> {
-    let encoded_list = base64::decode_config(
-        revocation_list.credential_subject.encoded_list.to_string(),
-        base64::URL_SAFE,
-    )?;
-    let mut decoder = GzDecoder::new(&encoded_list[..]);
-    let mut decoded_list = Vec::new();
-    decoder
-        .read_to_end(&mut decoded_list)
-        .map_err(|e| CredentialError::RevocationListInvalid(e.to_string()))?;
+    let list_type = get_status_list_type(revocation_list)?;
+    let decoded_list = decode_revocation_list(revocation_list)?;
 
     let revocation_list_index_number = credential_status
         .revocation_list_index
@@ -127,20 +810,148 @@ pub fn is_revoked(
             ))
         })?;
 
-    let byte_index_float: f32 = (revocation_list_index_number / 8) as f32;
-    let byte_index: usize = byte_index_float.floor() as usize;
-    let revoked = decoded_list[byte_index] & (1 << (revocation_list_index_number % 8)) != 0;
+    let byte_index = revocation_list_index_number / 8;
+    let byte = decoded_list.get(byte_index).ok_or_else(|| {
+        CredentialError::RevocationListInvalid(format!(
+            "revocation list index {} is out of range for a list of {} entries",
+            revocation_list_index_number,
+            decoded_list.len() * 8
+        ))
+    })?;
+    let revoked = byte & get_status_list_mask(list_type, revocation_list_index_number) != 0;
 
     Ok(revoked)
 }
 
+/// Revocation state of a credential, as reported by
+/// [`VadeEvan::helper_revocation_status`](crate::VadeEvan::helper_revocation_status).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RevocationState {
+    /// credential has no status or is not flagged in its revocation list
+    Active,
+    /// credential is flagged as revoked in its revocation list
+    Revoked,
+    /// revocation list of the credential could not be resolved
+    Unknown,
+}
+
+/// Maps an attribute of a credential to the nquad and BBS message it is signed in, as returned
+/// by [`VadeEvan::helper_attribute_mapping`](crate::VadeEvan::helper_attribute_mapping).
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeMapping {
+    pub attribute: String,
+    pub nquad: String,
+    pub message_index: usize,
+}
+
+/// Options for [`Credential::create_credential_offer_with_options`].
+#[derive(Clone, Debug, Default)]
+pub struct CredentialOfferOptions {
+    /// resolve the issuer DID and ensure, that it provides a key to sign credentials with; offers
+    /// cannot be created offline if enabled
+    pub validate_issuer: bool,
+    /// reject draft credentials with properties not defined in their context, as these would be
+    /// dropped during canonicalization and not be signed
+    pub check_undefined_terms: bool,
+}
+
+/// Proof of a credential derived from a BBS signature, e.g. taken from a presentation. It proves
+/// knowledge of the signature for the revealed messages, bound to the nonce of a proof request.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DerivedBbsProof {
+    proof_purpose: String,
+    verification_method: String,
+    credential_message_count: usize,
+    nonce: String,
+    proof: String,
+}
+
+/// Builds an [`OfferCredentialPayload`] for a draft credential, validating the draft before it
+/// is used for an offer that could otherwise not be fulfilled.
+pub struct OfferCredentialPayloadBuilder {
+    draft_credential: UnsignedBbsCredential,
+    credential_status_type: LdProofVcDetailOptionsCredentialStatusType,
+    required_reveal_statements: Vec<u32>,
+}
+
+impl OfferCredentialPayloadBuilder {
+    pub fn new(draft_credential: UnsignedBbsCredential) -> Self {
+        Self {
+            draft_credential,
+            credential_status_type: LdProofVcDetailOptionsCredentialStatusType::None,
+            required_reveal_statements: vec![],
+        }
+    }
+
+    pub fn credential_status_included(mut self, is_credential_status_included: bool) -> Self {
+        self.credential_status_type = match is_credential_status_included {
+            true => LdProofVcDetailOptionsCredentialStatusType::RevocationList2021Status,
+            false => LdProofVcDetailOptionsCredentialStatusType::None,
+        };
+        self
+    }
+
+    pub fn required_reveal_statements(mut self, required_reveal_statements: Vec<u32>) -> Self {
+        self.required_reveal_statements = required_reveal_statements;
+        self
+    }
+
+    /// Validates the draft credential and builds the payload.
+    ///
+    /// # Returns
+    /// * `OfferCredentialPayload` - payload for `vc_zkp_create_credential_offer`
+    pub fn build(self) -> Result<OfferCredentialPayload, CredentialError> {
+        fail_if_not_a_did(&self.draft_credential.issuer, "issuer")?;
+        if let Some(subject) = &self.draft_credential.credential_subject.id {
+            fail_if_not_a_did(subject, "subject")?;
+        }
+        if self.draft_credential.credential_subject.data.is_empty() {
+            return Err(CredentialError::InvalidCredentialOffer(
+                "draft credential does not contain any claims".to_string(),
+            ));
+        }
+
+        Ok(OfferCredentialPayload {
+            draft_credential: self.draft_credential,
+            credential_status_type: self.credential_status_type,
+            required_reveal_statements: self.required_reveal_statements,
+        })
+    }
+}
+
 pub struct Credential<'a> {
     vade_evan: &'a mut VadeEvan,
+    check_schema_properties: bool,
+    check_signed_id: bool,
 }
 
 impl<'a> Credential<'a> {
     pub fn new(vade_evan: &'a mut VadeEvan) -> Result<Credential, CredentialError> {
-        Ok(Credential { vade_evan })
+        let check_schema_properties = vade_evan.get_schema_property_check();
+        let check_signed_id = vade_evan.get_signed_id_check();
+        Ok(Credential {
+            vade_evan,
+            check_schema_properties,
+            check_signed_id,
+        })
+    }
+
+    /// Enables checking credentials against their schema during verification, so properties not
+    /// defined in schemas that disallow additional properties are rejected. Defaults to the
+    /// setting of the [`VadeEvan`] instance, see [`VadeEvan::set_schema_property_check`].
+    pub fn set_schema_property_check(&mut self, enabled: bool) {
+        self.check_schema_properties = enabled;
+    }
+
+    /// Enables rejecting credentials whose `id` is not covered by their signature during
+    /// verification. Absolute IRI ids, like the "uuid:..." ids set in unsigned credentials, are
+    /// signed, so changing them invalidates the signature anyway. Other ids are dropped during
+    /// canonicalization and could be changed after issuance unnoticed. Defaults to the setting of
+    /// the [`VadeEvan`] instance, see [`VadeEvan::set_signed_id_check`].
+    pub fn set_signed_id_check(&mut self, enabled: bool) {
+        self.check_signed_id = enabled;
     }
 
     pub async fn create_credential_offer(
@@ -150,14 +961,39 @@ impl<'a> Credential<'a> {
         issuer_did: &str,
         is_credential_status_included: bool,
         required_reveal_statements: &str,
+    ) -> Result<String, CredentialError> {
+        self.create_credential_offer_with_options(
+            schema_did,
+            use_valid_until,
+            issuer_did,
+            is_credential_status_included,
+            required_reveal_statements,
+            &CredentialOfferOptions::default(),
+        )
+        .await
+    }
+
+    /// Creates a credential offer like [`create_credential_offer`](Self::create_credential_offer),
+    /// with additional checks configured by `options`.
+    pub async fn create_credential_offer_with_options(
+        &mut self,
+        schema_did: &str,
+        use_valid_until: bool,
+        issuer_did: &str,
+        is_credential_status_included: bool,
+        required_reveal_statements: &str,
+        options: &CredentialOfferOptions,
     ) -> Result<String, CredentialError> {
         fail_if_not_a_did(schema_did, "schema_did")?;
         fail_if_not_a_did(issuer_did, "issuer_did")?;
-        let schema: CredentialSchema = self.get_did_document(schema_did).await?;
+        if options.validate_issuer {
+            self.check_issuer(issuer_did).await?;
+        }
+        let schema = self.get_schema(schema_did).await?;
         let required_reveal_statements: Vec<u32> = serde_json::from_str(required_reveal_statements)
             .map_err(|err| CredentialError::JsonDeSerialization(err))?;
-        let payload = OfferCredentialPayload {
-            draft_credential: schema.to_draft_credential(CredentialDraftOptions {
+        let payload = OfferCredentialPayloadBuilder::new(schema.to_draft_credential(
+            CredentialDraftOptions {
                 issuer_did: issuer_did.to_string(),
                 id: None,
                 issuance_date: None,
@@ -165,13 +1001,14 @@ impl<'a> Credential<'a> {
                     true => Some("".to_owned()),
                     false => None,
                 },
-            }),
-            credential_status_type: match is_credential_status_included {
-                true => LdProofVcDetailOptionsCredentialStatusType::RevocationList2021Status,
-                false => LdProofVcDetailOptionsCredentialStatusType::None,
             },
-            required_reveal_statements,
-        };
+        ))
+        .credential_status_included(is_credential_status_included)
+        .required_reveal_statements(required_reveal_statements)
+        .build()?;
+        if options.check_undefined_terms {
+            convert_to_nquads_strict(&serde_json::to_string(&payload.draft_credential)?).await?;
+        }
 
         let result = self
             .vade_evan
@@ -186,6 +1023,76 @@ impl<'a> Credential<'a> {
         Ok(result)
     }
 
+    /// Reads the nonce from a credential offer, that the holder has to use when requesting the
+    /// offered credential.
+    ///
+    /// # Arguments
+    /// * `offer_str` - credential offer as JSON string
+    ///
+    /// # Returns
+    /// * nonce of the offer
+    pub fn get_offer_nonce(offer_str: &str) -> Result<String, CredentialError> {
+        let offer: BbsCredentialOffer = serde_json::from_str(offer_str)
+            .map_err(|err| CredentialError::InvalidCredentialOffer(err.to_string()))?;
+
+        Ok(offer.nonce)
+    }
+
+    /// Checks that a credential offer is consistent with the schema it references. The claims of
+    /// the offered draft credential determine the number of signed messages, so they have to
+    /// match the schema properties exactly.
+    ///
+    /// # Arguments
+    /// * `offer_str` - credential offer as JSON string
+    pub async fn validate_offer(&mut self, offer_str: &str) -> Result<(), CredentialError> {
+        let offer: BbsCredentialOffer = serde_json::from_str(offer_str)
+            .map_err(|err| CredentialError::InvalidCredentialOffer(err.to_string()))?;
+        let draft_credential = &offer.ld_proof_vc_detail.credential;
+        let schema = self
+            .get_schema(&draft_credential.credential_schema.id)
+            .await?;
+
+        let mut offered_claims: Vec<&String> =
+            draft_credential.credential_subject.data.keys().collect();
+        let mut schema_properties: Vec<&String> = schema.properties.keys().collect();
+        offered_claims.sort();
+        schema_properties.sort();
+        if offered_claims != schema_properties {
+            return Err(CredentialError::InvalidCredentialOffer(format!(
+                "offer has {} claims {:?}, but schema {} defines {} properties {:?}",
+                offered_claims.len(),
+                offered_claims,
+                schema.id,
+                schema_properties.len(),
+                schema_properties,
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Redacts a credential or a presentation for logging. Claim values in
+    /// `credentialSubject.data` are replaced and signatures are truncated, while structural
+    /// fields like issuer, schema and status are kept.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential or presentation as JSON string
+    ///
+    /// # Returns
+    /// * redacted credential or presentation as JSON string
+    pub fn redact_for_logging(credential_str: &str) -> Result<String, CredentialError> {
+        let mut value: Value = serde_json::from_str(credential_str)?;
+        if let Some(credentials) = value
+            .get_mut("verifiableCredential")
+            .and_then(|credentials| credentials.as_array_mut())
+        {
+            credentials.iter_mut().for_each(redact_credential);
+        }
+        redact_credential(&mut value);
+
+        Ok(serde_json::to_string(&value)?)
+    }
+
     pub async fn create_credential_request(
         &mut self,
         issuer_public_key: &str,
@@ -195,8 +1102,7 @@ impl<'a> Credential<'a> {
         credential_schema_did: &str,
     ) -> Result<String, CredentialError> {
         fail_if_not_a_did(credential_schema_did, "credential_schema_did")?;
-        let credential_schema: CredentialSchema =
-            self.get_did_document(credential_schema_did).await?;
+        let credential_schema = self.get_schema(credential_schema_did).await?;
 
         let payload = format!(
             r#"{{
@@ -221,32 +1127,178 @@ impl<'a> Credential<'a> {
         Ok(result)
     }
 
+    /// Verifies a credential, selecting the verification based on the credential's proof type.
+    /// If the credential has multiple proofs, all of them have to be valid. The credential `id`
+    /// has to be signed as well if enabled, see `set_signed_id_check`. Properties are checked
+    /// against the credential schema if enabled, see `set_schema_property_check`.
+    /// Successful verifications may be cached, see `VadeEvan::set_verification_cache_ttl`. The
+    /// revocation status of cached credentials is checked again on every verification.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential to verify in seralized string format
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    /// * `expected_subject` - if given, `credentialSubject.id` has to match this DID
     pub async fn verify_credential(
         &mut self,
         credential_str: &str,
         master_secret: &str,
+        expected_subject: Option<&str>,
     ) -> Result<(), CredentialError> {
-        let credential: BbsCredential = serde_json::from_str(credential_str)?;
-
-        // get nquads
-        let mut parsed_credential: Map<String, Value> = serde_json::from_str(credential_str)?;
-        parsed_credential.remove("proof");
-        let credential_without_proof = serde_json::to_string(&parsed_credential)?;
-        let did_doc_nquads = convert_to_nquads(&credential_without_proof).await?;
+        let flags = VerificationFlags {
+            check_signed_id: self.check_signed_id,
+            check_schema_properties: self.check_schema_properties,
+        };
+        let cache_key = self
+            .vade_evan
+            .get_verification_cache()
+            .is_enabled()
+            .then(|| get_verification_cache_key(credential_str, master_secret, flags));
+        if let Some(cache_key) = &cache_key {
+            if self.vade_evan.get_verification_cache().contains(cache_key) {
+                let credential_value: Value = serde_json::from_str(credential_str)?;
+                let credential_status: Option<CredentialStatus> =
+                    serde_json::from_value(credential_value["credentialStatus"].clone())?;
+                self.fail_if_revoked(credential_status.as_ref()).await?;
+                self.fail_if_unexpected_property(credential_str).await?;
+                let credential_subject = get_credential_subject(credential_str)?;
+                return fail_on_subject_mismatch(&credential_subject, expected_subject);
+            }
+        }
 
-        if (did_doc_nquads.len() + ADDITIONAL_HIDDEN_MESSAGES_COUNT)
-            != credential.proof.credential_message_count
-        {
-            return Err(CredentialError::MessageCountMismatch(
-                credential.proof.credential_message_count,
-                did_doc_nquads.len() + ADDITIONAL_HIDDEN_MESSAGES_COUNT,
-            ));
+        self.verify_all_proofs(credential_str, master_secret).await?;
+        if let Some(cache_key) = &cache_key {
+            self.vade_evan.get_verification_cache().insert(cache_key);
         }
+        fail_on_subject_mismatch(&get_credential_subject(credential_str)?, expected_subject)?;
 
-        // get public key suitable for messages
-        let verification_method_id = credential
-            .proof
-            .verification_method
+        Ok(())
+    }
+
+    /// Verifies a credential like [`verify_credential`](Self::verify_credential) and returns
+    /// the parsed credential if it is valid.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential to verify in seralized string format
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    ///
+    /// # Returns
+    /// * `BbsCredential` - the verified credential, with its first proof if it has multiple; fails
+    ///   for credentials with derived proofs, as these cannot be parsed as `BbsCredential`
+    pub async fn verify_credential_and_parse(
+        &mut self,
+        credential_str: &str,
+        master_secret: &str,
+    ) -> Result<BbsCredential, CredentialError> {
+        self.verify_all_proofs(credential_str, master_secret).await?;
+
+        Ok(serde_json::from_value(split_proofs(credential_str)?.remove(0))?)
+    }
+
+    async fn verify_all_proofs(
+        &mut self,
+        credential_str: &str,
+        master_secret: &str,
+    ) -> Result<(), CredentialError> {
+        for credential_with_proof in split_proofs(credential_str)? {
+            self.verify_single_proof(credential_with_proof, credential_str, master_secret)
+                .await?;
+        }
+
+        self.fail_if_unexpected_property(credential_str).await
+    }
+
+    async fn fail_if_unexpected_property(
+        &mut self,
+        credential_str: &str,
+    ) -> Result<(), CredentialError> {
+        if !self.check_schema_properties {
+            return Ok(());
+        }
+        let credential_value: Value = serde_json::from_str(credential_str)?;
+        let schema_did = credential_value["credentialSchema"]["id"]
+            .as_str()
+            .ok_or_else(|| {
+                CredentialError::InvalidCredentialSchema("credential has no schema id".to_string())
+            })?;
+        let schema: Value = self.get_did_document(schema_did).await?;
+
+        fail_on_unexpected_property(&schema, &credential_value)
+    }
+
+    async fn verify_single_proof(
+        &mut self,
+        parsed_credential: Value,
+        credential_str: &str,
+        master_secret: &str,
+    ) -> Result<(), CredentialError> {
+        let proof_type = parsed_credential["proof"]["type"].as_str().unwrap_or_default();
+
+        match proof_type {
+            PROOF_TYPE_BBS => {
+                self.verify_bbs_credential(parsed_credential, credential_str, master_secret)
+                    .await
+            }
+            PROOF_TYPE_BBS_DERIVED => {
+                self.verify_derived_bbs_credential(&parsed_credential, credential_str)
+                    .await
+            }
+            _ => Err(CredentialError::UnsupportedProofType(proof_type.to_string())),
+        }
+    }
+
+    /// Runs the checks shared by all verifications of `BbsBlsSignature2020` proofs before any
+    /// issuer key is resolved, so invalid credentials fail without a network round trip: proof
+    /// type, `@context`, timestamps, signed messages, master secret and signature encoding.
+    ///
+    /// # Arguments
+    /// * `credential_value` - credential to verify, with a single proof
+    /// * `credential_str` - credential to verify in seralized string format
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential, or
+    ///   `None` for credentials signed without a master secret
+    ///
+    /// # Returns
+    /// * `(BbsCredential, Vec<String>)` - parsed credential and its nquads without proof
+    async fn pre_verify_bbs_credential(
+        &self,
+        credential_value: Value,
+        credential_str: &str,
+        master_secret: Option<&str>,
+    ) -> Result<(BbsCredential, Vec<String>), CredentialError> {
+        let proof_type = credential_value["proof"]["type"].as_str().unwrap_or_default();
+        if proof_type != PROOF_TYPE_BBS {
+            return Err(CredentialError::UnsupportedProofType(proof_type.to_string()));
+        }
+        fail_on_context_mismatch(&credential_value)?;
+        fail_on_invalid_timestamps(&credential_value)?;
+        let credential: BbsCredential = serde_json::from_value(credential_value)?;
+        let did_doc_nquads = match master_secret {
+            Some(master_secret) => {
+                let did_doc_nquads =
+                    get_verified_nquads(&credential, credential_str, self.check_signed_id).await?;
+                get_master_secret_message(master_secret)?;
+                did_doc_nquads
+            }
+            None => {
+                get_checked_nquads(&credential, credential_str, false, self.check_signed_id).await?
+            }
+        };
+        parse_signature(&decode_base64(&credential.proof.signature)?)?;
+
+        Ok((credential, did_doc_nquads))
+    }
+
+    async fn verify_bbs_credential(
+        &mut self,
+        credential_value: Value,
+        credential_str: &str,
+        master_secret: &str,
+    ) -> Result<(), CredentialError> {
+        let (credential, did_doc_nquads) = self
+            .pre_verify_bbs_credential(credential_value, credential_str, Some(master_secret))
+            .await?;
+        let verification_method_id = credential
+            .proof
+            .verification_method
             .rsplit_once('#')
             .ok_or_else(|| {
                 CredentialError::InvalidVerificationMethod(
@@ -254,31 +1306,75 @@ impl<'a> Credential<'a> {
                 )
             })?
             .1;
+
+        // get public key suitable for messages
         let issuer_pub_key = self
-            .get_issuer_public_key(&credential.issuer, &format!("#{}", verification_method_id))
+            .get_issuer_public_key_for_purpose(
+                &credential.issuer,
+                &format!("#{}", verification_method_id),
+                &credential.proof.proof_purpose,
+            )
             .await?;
-        let public_key_generator = get_public_key_generator(
-            &issuer_pub_key,
-            did_doc_nquads.len() + ADDITIONAL_HIDDEN_MESSAGES_COUNT,
-        )?;
 
         // verify signature
-        self.verify_proof_signature(
-            &credential.proof.signature,
+        self.verify_signature_with_key(
+            &credential,
             &did_doc_nquads,
-            master_secret,
-            &public_key_generator,
+            &issuer_pub_key,
+            Some(master_secret),
         )
         .await?;
 
-        if credential.credential_status.is_some() {
-            let credential_status = &credential.credential_status.ok_or_else(|| {
-                CredentialError::InvalidCredentialStatus(
-                    "Error in parsing credential_status".to_string(),
+        self.fail_if_revoked(credential.credential_status.as_ref()).await
+    }
+
+    /// Verifies a credential with a derived proof. The proof is bound to the nonce it carries, so
+    /// it can be verified without the proof request it has been created for, as long as it
+    /// reveals all attributes of the credential.
+    async fn verify_derived_bbs_credential(
+        &mut self,
+        credential_value: &Value,
+        credential_str: &str,
+    ) -> Result<(), CredentialError> {
+        fail_on_context_mismatch(credential_value)?;
+        fail_on_invalid_timestamps(credential_value)?;
+        let proof: DerivedBbsProof = serde_json::from_value(credential_value["proof"].clone())?;
+        let nquads = get_nquads_without_proof(credential_str).await?;
+        let revealed_messages = get_revealed_messages(&nquads, &proof)?;
+        let issuer = credential_value["issuer"].as_str().ok_or_else(|| {
+            CredentialError::InvalidIssuer("credential has no issuer".to_string())
+        })?;
+        let verification_method_id = proof
+            .verification_method
+            .rsplit_once('#')
+            .ok_or_else(|| {
+                CredentialError::InvalidVerificationMethod(
+                    "invalid verification method in proof".to_string(),
                 )
-            })?;
+            })?
+            .1;
+
+        let issuer_pub_key = self
+            .get_issuer_public_key_for_purpose(
+                issuer,
+                &format!("#{}", verification_method_id),
+                &proof.proof_purpose,
+            )
+            .await?;
+        verify_derived_proof(&proof, revealed_messages, &issuer_pub_key)?;
+
+        let credential_status: Option<CredentialStatus> =
+            serde_json::from_value(credential_value["credentialStatus"].clone())?;
+        self.fail_if_revoked(credential_status.as_ref()).await
+    }
+
+    async fn fail_if_revoked(
+        &mut self,
+        credential_status: Option<&CredentialStatus>,
+    ) -> Result<(), CredentialError> {
+        if let Some(credential_status) = credential_status {
             // resolve the did and extract the did document out of it
-            let revocation_list: RevocationListCredential = self
+            let revocation_list: Value = self
                 .get_did_document(&credential_status.revocation_list_credential)
                 .await?;
             let credential_revoked = is_revoked(credential_status, &revocation_list)?;
@@ -290,6 +1386,376 @@ impl<'a> Credential<'a> {
         Ok(())
     }
 
+    /// Resolves an issuer public key and checks that it can be used to verify credentials with
+    /// the given number of messages, so callers can fail before verifying a credential.
+    ///
+    /// # Arguments
+    /// * `issuer_did` - DID of the issuer
+    /// * `verification_method_id` - id of the issuer's verification method, e.g. "#bbs-key-1"
+    /// * `expected_message_count` - number of signed messages, including the master secret
+    pub async fn validate_issuer_key(
+        &mut self,
+        issuer_did: &str,
+        verification_method_id: &str,
+        expected_message_count: usize,
+    ) -> Result<(), CredentialError> {
+        fail_if_not_a_did(issuer_did, "issuer_did")?;
+        let public_key = self.get_issuer_public_key(issuer_did, verification_method_id).await?;
+
+        validate_public_key(&public_key, expected_message_count)
+    }
+
+    /// Verifies a credential like [`verify_credential`](Self::verify_credential), but tries
+    /// multiple verification methods of the issuer instead of the one named in the proof, e.g.
+    /// to verify credentials signed before the issuer rotated its keys. Candidates that cannot
+    /// be resolved for the proof purpose of the credential are skipped.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential to verify in seralized string format
+    /// * `candidate_method_ids` - ids of issuer verification methods to try, e.g. "#bbs-key-1"
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    ///
+    /// # Returns
+    /// * `String` - id of the verification method the signature has been verified with
+    pub async fn verify_credential_any_key(
+        &mut self,
+        credential_str: &str,
+        candidate_method_ids: &[&str],
+        master_secret: &str,
+    ) -> Result<String, CredentialError> {
+        let (credential, did_doc_nquads) = self
+            .pre_verify_bbs_credential(
+                serde_json::from_str(credential_str)?,
+                credential_str,
+                Some(master_secret),
+            )
+            .await?;
+
+        for method_id in candidate_method_ids {
+            let issuer_pub_key = match self
+                .get_issuer_public_key_for_purpose(
+                    &credential.issuer,
+                    method_id,
+                    &credential.proof.proof_purpose,
+                )
+                .await
+            {
+                Ok(issuer_pub_key) => issuer_pub_key,
+                Err(_) => continue,
+            };
+            if self
+                .verify_signature_with_key(
+                    &credential,
+                    &did_doc_nquads,
+                    &issuer_pub_key,
+                    Some(master_secret),
+                )
+                .await
+                .is_ok()
+            {
+                self.fail_if_revoked(credential.credential_status.as_ref())
+                    .await?;
+                return Ok(method_id.to_string());
+            }
+        }
+
+        Err(CredentialError::NoMatchingIssuerKey(format!(
+            "signature could not be verified with any of {}",
+            candidate_method_ids.join(", ")
+        )))
+    }
+
+    /// Verifies the signature of a credential against a given issuer public key.
+    /// In contrast to [`verify_credential`](Self::verify_credential), neither the issuer DID nor
+    /// the revocation list are resolved, so this can be used by verifiers without network access
+    /// that have trusted issuer keys provisioned beforehand. Proof type, context and timestamps
+    /// are checked as in [`verify_credential`](Self::verify_credential), revocation status is not.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential to verify in seralized string format
+    /// * `issuer_public_key` - base64 encoded bbs public key of the issuer
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    pub async fn verify_credential_with_key(
+        &self,
+        credential_str: &str,
+        issuer_public_key: &str,
+        master_secret: &str,
+    ) -> Result<(), CredentialError> {
+        let credential_value: Value = serde_json::from_str(credential_str)?;
+        let (credential, did_doc_nquads) = self
+            .pre_verify_bbs_credential(credential_value, credential_str, Some(master_secret))
+            .await?;
+
+        self.verify_signature_with_key(
+            &credential,
+            &did_doc_nquads,
+            issuer_public_key,
+            Some(master_secret),
+        )
+        .await
+    }
+
+    /// Verifies the signature of a credential signed without a master secret, e.g. a credential
+    /// issued to a machine, against a given issuer public key. Like
+    /// [`verify_credential_with_key`](Self::verify_credential_with_key), the credential is
+    /// pre-verified, but the issuer DID and the revocation list are not resolved. Credentials
+    /// bound to a master secret are rejected with [`CredentialError::MessageCountMismatch`].
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential to verify in seralized string format
+    /// * `issuer_public_key` - base64 encoded bbs public key of the issuer
+    pub async fn verify_unbound_credential_with_key(
+        &self,
+        credential_str: &str,
+        issuer_public_key: &str,
+    ) -> Result<(), CredentialError> {
+        let credential_value: Value = serde_json::from_str(credential_str)?;
+        let (credential, did_doc_nquads) = self
+            .pre_verify_bbs_credential(credential_value, credential_str, None)
+            .await?;
+
+        self.verify_signature_with_key(&credential, &did_doc_nquads, issuer_public_key, None)
+            .await
+    }
+
+    /// Verifies a credential with a derived `BbsBlsSignatureProof2020` proof, e.g. taken from a
+    /// presentation, against a given issuer public key. The proof is checked against the nonce it
+    /// carries and the credential's nquads as revealed messages, so all attributes have to be
+    /// revealed. Revocation status is not checked.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential with derived proof in seralized string format
+    /// * `issuer_public_key` - base64 encoded bbs public key of the issuer
+    pub async fn verify_derived_credential_with_key(
+        &self,
+        credential_str: &str,
+        issuer_public_key: &str,
+    ) -> Result<(), CredentialError> {
+        let credential_value: Value = serde_json::from_str(credential_str)?;
+        let proof: DerivedBbsProof = serde_json::from_value(credential_value["proof"].clone())?;
+        let nquads = get_nquads_without_proof(credential_str).await?;
+
+        verify_derived_proof(&proof, get_revealed_messages(&nquads, &proof)?, issuer_public_key)
+    }
+
+    /// Verifies a credential against an issuer DID document held by the caller, e.g. provided by a
+    /// trust registry, instead of resolving the issuer DID. The verification method has to be
+    /// authorized for the proof purpose of the credential in the given document. Revocation
+    /// status is not checked.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential to verify in seralized string format
+    /// * `issuer_document` - DID document of the credential issuer
+    /// * `verification_method_id` - id of the issuer's verification method, e.g. "#bbs-key-1"
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    pub async fn verify_credential_with_document(
+        &self,
+        credential_str: &str,
+        issuer_document: &IdentityDidDocument,
+        verification_method_id: &str,
+        master_secret: &str,
+    ) -> Result<(), CredentialError> {
+        let (credential, did_doc_nquads) = self
+            .pre_verify_bbs_credential(
+                serde_json::from_str(credential_str)?,
+                credential_str,
+                Some(master_secret),
+            )
+            .await?;
+        if issuer_document.id != credential.issuer {
+            return Err(CredentialError::InvalidIssuer(format!(
+                "given DID document of {} does not belong to credential issuer {}",
+                issuer_document.id, credential.issuer
+            )));
+        }
+        fail_on_duplicate_verification_method_ids(issuer_document)?;
+        check_verification_method_purpose(
+            issuer_document,
+            verification_method_id,
+            &credential.proof.proof_purpose,
+        )?;
+        let issuer_pub_key =
+            get_public_key_from_did_document(issuer_document.clone(), verification_method_id)?;
+
+        self.verify_signature_with_key(
+            &credential,
+            &did_doc_nquads,
+            &issuer_pub_key,
+            Some(master_secret),
+        )
+        .await
+    }
+
+    /// Verifies a credential signature against already canonicalized N-Quads of the credential
+    /// without its proof, skipping the JSON-LD processing done by
+    /// [`verify_credential_with_key`](Self::verify_credential_with_key).
+    ///
+    /// The caller is responsible for the N-Quads being the correct canonicalization of the
+    /// credential, in their original order. Neither the credential's `@context` nor its
+    /// timestamps or revocation status are checked.
+    ///
+    /// # Arguments
+    /// * `nquads` - canonicalized N-Quads of the credential without proof
+    /// * `proof` - proof of the credential
+    /// * `issuer_public_key` - base64 encoded bbs public key of the issuer
+    /// * `master_secret` - master secret incorporated as a blinded value into the credential
+    pub async fn verify_credential_with_nquads(
+        &self,
+        nquads: &[String],
+        proof: &BbsProof,
+        issuer_public_key: &str,
+        master_secret: &str,
+    ) -> Result<(), CredentialError> {
+        fail_on_message_count_mismatch(nquads, proof, true)?;
+        let master_secret = get_master_secret_message(master_secret)?;
+        let public_key_generator =
+            get_public_key_generator(issuer_public_key, proof.credential_message_count)?;
+
+        self.verify_proof_signature(
+            &proof.signature,
+            nquads,
+            Some(master_secret),
+            &public_key_generator,
+        )
+        .await
+    }
+
+    /// Gets the index of the BBS message, that contains an attribute of a credential, e.g. to
+    /// build `requiredRevealStatements` or selectively disclose attributes. The index accounts
+    /// for the hidden messages signed before the credential's nquads.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential in seralized string format, with or without proof
+    /// * `attribute_name` - name of the attribute, e.g. "bio"
+    ///
+    /// # Returns
+    /// * `usize` - index of the message
+    pub async fn attribute_index(
+        &self,
+        credential_str: &str,
+        attribute_name: &str,
+    ) -> Result<usize, CredentialError> {
+        let nquads = get_nquads_without_proof(credential_str).await?;
+
+        get_attribute_index(&nquads, attribute_name)
+            .ok_or_else(|| CredentialError::UnknownAttribute(attribute_name.to_string()))
+    }
+
+    /// Lists the nquad and BBS message index of each attribute in `credentialSubject.data`, so
+    /// issuers and verifiers can check that they agree on the canonicalization of a credential,
+    /// e.g. when debugging message count mismatches.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential in seralized string format, with or without proof
+    ///
+    /// # Returns
+    /// * `Vec<AttributeMapping>` - mapping for each attribute, in order of the credential data
+    pub async fn attribute_mapping(
+        &self,
+        credential_str: &str,
+    ) -> Result<Vec<AttributeMapping>, CredentialError> {
+        let credential: Value = serde_json::from_str(credential_str)?;
+        let nquads = get_nquads_without_proof(credential_str).await?;
+        let attributes: Vec<String> = match credential["credentialSubject"]["data"].as_object() {
+            Some(data) => data.keys().cloned().collect(),
+            None => vec![],
+        };
+
+        attributes
+            .into_iter()
+            .map(|attribute| {
+                let message_index = get_attribute_index(&nquads, &attribute)
+                    .ok_or_else(|| CredentialError::UnknownAttribute(attribute.clone()))?;
+                Ok(AttributeMapping {
+                    nquad: nquads[message_index - ADDITIONAL_HIDDEN_MESSAGES_COUNT].clone(),
+                    attribute,
+                    message_index,
+                })
+            })
+            .collect()
+    }
+
+    /// Finds the first index in a revocation list that is not flagged as revoked, so it can be
+    /// used as `revocationListIndex` for a new credential.
+    ///
+    /// Note that the revocation list only keeps track of revoked indices, so issuers still have
+    /// to make sure not to assign an index to multiple active credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `revocation_list_did` - DID of the revocation list to check
+    ///
+    /// # Returns
+    /// * `u32` - first free index in revocation list
+    pub async fn next_free_revocation_index(
+        &mut self,
+        revocation_list_did: &str,
+    ) -> Result<u32, CredentialError> {
+        fail_if_not_a_did(revocation_list_did, "revocation_list_did")?;
+        let revocation_list: Value = self.get_did_document(revocation_list_did).await?;
+        let decoded_list = decode_revocation_list(&revocation_list)?;
+
+        get_first_unset_index(&decoded_list).ok_or_else(|| {
+            CredentialError::RevocationListFull(format!(
+                "all {} indices of revocation list {} are in use",
+                decoded_list.len() * 8,
+                revocation_list_did,
+            ))
+        })
+    }
+
+    /// Lists the indices of all credentials flagged as revoked in a revocation list, supports
+    /// RevocationList2020 and StatusList2021 lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `revocation_list_did` - DID of the revocation list to check
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - revoked indices in ascending order
+    pub async fn list_revoked_indices(
+        &mut self,
+        revocation_list_did: &str,
+    ) -> Result<Vec<u32>, CredentialError> {
+        fail_if_not_a_did(revocation_list_did, "revocation_list_did")?;
+        let revocation_list: Value = self.get_did_document(revocation_list_did).await?;
+
+        get_revoked_indices(&revocation_list)
+    }
+
+    /// Checks the revocation status of a credential without verifying its signature, e.g. for
+    /// holders that want to know if a credential can still be presented.
+    ///
+    /// # Arguments
+    /// * `credential_str` - credential to check in seralized string format
+    ///
+    /// # Returns
+    /// * `RevocationState` - `Active` for credentials without `credentialStatus`, `Unknown` if
+    ///   the revocation list cannot be resolved; invalid or empty revocation lists are errors
+    pub async fn revocation_status(
+        &mut self,
+        credential_str: &str,
+    ) -> Result<RevocationState, CredentialError> {
+        let credential_value: Value = serde_json::from_str(credential_str)?;
+        let credential_status: CredentialStatus = match credential_value.get("credentialStatus") {
+            None | Some(Value::Null) => return Ok(RevocationState::Active),
+            Some(credential_status) => serde_json::from_value(credential_status.clone())?,
+        };
+        let revocation_list_did = &credential_status.revocation_list_credential;
+        fail_if_not_a_did(revocation_list_did, "revocation list did")?;
+        let did_result_str = match self.vade_evan.did_resolve(revocation_list_did).await {
+            Ok(did_result_str) => did_result_str,
+            Err(_) => return Ok(RevocationState::Unknown),
+        };
+        let revocation_list: Value = parse_did_document(revocation_list_did, &did_result_str)
+            .map_err(|err| CredentialError::VadeEvanError(err.to_string()))?;
+
+        match is_revoked(&credential_status, &revocation_list)? {
+            true => Ok(RevocationState::Revoked),
+            false => Ok(RevocationState::Active),
+        }
+    }
+
     /// Revokes a given credential with the help of vade and updates revocation list credential
     ///
     /// # Arguments
@@ -347,25 +1813,192 @@ impl<'a> Credential<'a> {
         Ok(update_result)
     }
 
-    /// Creates a new zero-knowledge proof self issued credential.
-    /// `create_self_issued_credential` function combines `vc_zkp_create_credential_offer`,
-    /// `vc_zkp_create_credential_request`, `vc_zkp_issue_credential` and `vc_zkp_finish_credential`
-    /// and produces self-issued credential with blind signature.
+    /// Revokes multiple credentials of a revocation list with a single DID update, e.g. to
+    /// revoke a batch of leaked credentials at once.
     ///
     /// # Arguments
-    ///
-    /// * `schema_did` - schema to create the credential
-    /// * `credential_subject_str` - JSON string of CredentialSubject structure
-    /// * `bbs_secret` - BBS secret
-    /// * `bbs_private_key` - BBS private key
-    /// * `credential_revocation_did` - revocation list DID (or `None` if no revocation is used)
-    /// * `credential_revocation_id` - index in revocation list (or `None` if no revocation is used)
-    /// * `exp_date` - expiration date, string, e.g. "1722-12-03T14:23:42.120Z" (or `None` if no expiration date is used)
-    /// * `subject_did` - subject did for self issued credential
+    /// * `revocation_list_did` - DID of the revocation list
+    /// * `indices` - revocation list indices of the credentials to revoke
+    /// * `update_key_jwk` - public key in jwk format to sign did update
     ///
     /// # Returns
-    /// * credential as JSON serialized [`BbsCredential`](https://docs.rs/vade_evan_bbs/*/vade_evan_bbs/struct.BbsCredential.html)
-    pub async fn create_self_issued_credential(
+    /// * `String` - the result of updated revocation list doc after credential revocation
+    #[cfg(feature = "did-sidetree")]
+    pub async fn revoke_many(
+        &mut self,
+        revocation_list_did: &str,
+        indices: &[u32],
+        update_key_jwk: &str,
+    ) -> Result<String, CredentialError> {
+        fail_if_not_a_did(revocation_list_did, "revocation_list_did")?;
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        let revocation_list_value: Value = self.get_did_document(revocation_list_did).await?;
+        let capacity = decode_revocation_list(&revocation_list_value)?.len() * 8;
+        let mut revocation_list: RevocationListCredential =
+            serde_json::from_value(revocation_list_value)?;
+        match indices.last() {
+            None => {
+                return Err(CredentialError::RevocationIndexInvalid(
+                    "no indices given to revoke".to_string(),
+                ))
+            }
+            Some(index) if *index as usize >= capacity => {
+                return Err(CredentialError::RevocationIndexInvalid(format!(
+                    "index {} exceeds capacity {} of revocation list {}",
+                    index, capacity, revocation_list_did,
+                )))
+            }
+            Some(_) => (),
+        };
+
+        // revoke all indices locally, so the list is written only once
+        for index in indices {
+            let payload = RevokeCredentialPayload {
+                issuer: revocation_list.issuer.clone(),
+                revocation_list,
+                revocation_id: index.to_string(),
+                revocation_list_proof_keys: None,
+            };
+            let updated_revocation_list = self
+                .vade_evan
+                .vc_zkp_revoke_credential(
+                    EVAN_METHOD,
+                    TYPE_OPTIONS,
+                    &serde_json::to_string(&payload)?,
+                )
+                .await
+                .map_err(|err| CredentialError::VadeEvanError(err.to_string()))?;
+            revocation_list = serde_json::from_str(&updated_revocation_list)?;
+        }
+
+        self.vade_evan
+            .helper_did_update(
+                &revocation_list.id,
+                "ReplaceDidDoc",
+                update_key_jwk,
+                &serde_json::to_string(&revocation_list)?,
+            )
+            .await
+            .map_err(|err| CredentialError::VadeEvanError(err.to_string()))
+    }
+
+    /// Wraps a credential or a presentation in a JWT verifiable presentation signed by the holder.
+    /// Credentials are embedded into a new presentation, presentations are used as they are.
+    ///
+    /// # Arguments
+    /// * `credential_or_presentation` - credential or presentation as JSON string
+    /// * `holder_key` - hex encoded private key of the holder to sign the JWT with
+    ///
+    /// # Returns
+    /// * JWT with the presentation given as `vp` claim
+    pub async fn to_jwt_vp(
+        &self,
+        credential_or_presentation: &str,
+        holder_key: &str,
+    ) -> Result<String, CredentialError> {
+        let document: Value = serde_json::from_str(credential_or_presentation)?;
+        let is_presentation = document["type"]
+            .as_array()
+            .map(|types| types.iter().any(|t| t == "VerifiablePresentation"))
+            .unwrap_or(false);
+        let presentation = if is_presentation {
+            document
+        } else {
+            json!({
+                "@context": ["https://www.w3.org/2018/credentials/v1"],
+                "type": ["VerifiablePresentation"],
+                "verifiableCredential": [document],
+            })
+        };
+        let claims = json!({
+            "iat": Utc::now().timestamp(),
+            "vp": presentation,
+        });
+
+        let header_and_claims = format!(
+            "{}.{}",
+            encode_config(JWT_HEADER, URL_SAFE_NO_PAD),
+            encode_config(serde_json::to_string(&claims)?, URL_SAFE_NO_PAD),
+        );
+        let (signature, _) = LocalSigner::new()
+            .sign_message(&header_and_claims, holder_key)
+            .await
+            .map_err(|err| CredentialError::InvalidJwtPresentation(err.to_string()))?;
+
+        Ok(format!(
+            "{}.{}",
+            header_and_claims,
+            encode_config(signature, URL_SAFE_NO_PAD),
+        ))
+    }
+
+    /// Extracts the presentation from a JWT verifiable presentation and checks that it has been
+    /// signed by the expected holder.
+    ///
+    /// # Arguments
+    /// * `jwt_vp` - JWT verifiable presentation
+    /// * `holder_address` - address of the key the holder signed the JWT with
+    ///
+    /// # Returns
+    /// * embedded credential, if the presentation holds exactly one credential, otherwise the
+    ///   embedded presentation as JSON string
+    pub fn from_jwt_vp(
+        &self,
+        jwt_vp: &str,
+        holder_address: &str,
+    ) -> Result<String, CredentialError> {
+        let (signer_address, _) = recover_address_and_data(jwt_vp)
+            .map_err(|err| CredentialError::InvalidJwtPresentation(err.to_string()))?;
+        if signer_address.trim_start_matches("0x").to_lowercase()
+            != holder_address.trim_start_matches("0x").to_lowercase()
+        {
+            return Err(CredentialError::InvalidJwtPresentation(format!(
+                "signed by 0x{} instead of holder {}",
+                signer_address.trim_start_matches("0x"),
+                holder_address
+            )));
+        }
+
+        let encoded_claims = jwt_vp.split('.').nth(1).ok_or_else(|| {
+            CredentialError::InvalidJwtPresentation("JWT has no claims".to_string())
+        })?;
+        let claims: Value = serde_json::from_slice(&decode_base64(encoded_claims)?)?;
+        let presentation = &claims["vp"];
+        if !presentation.is_object() {
+            return Err(CredentialError::InvalidJwtPresentation(
+                "JWT has no vp claim".to_string(),
+            ));
+        }
+
+        match presentation["verifiableCredential"].as_array() {
+            Some(credentials) if credentials.len() == 1 => {
+                Ok(serde_json::to_string(&credentials[0])?)
+            }
+            _ => Ok(serde_json::to_string(presentation)?),
+        }
+    }
+
+    /// Creates a new zero-knowledge proof self issued credential.
+    /// `create_self_issued_credential` function combines `vc_zkp_create_credential_offer`,
+    /// `vc_zkp_create_credential_request`, `vc_zkp_issue_credential` and `vc_zkp_finish_credential`
+    /// and produces self-issued credential with blind signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_did` - schema to create the credential
+    /// * `credential_subject_str` - JSON string of CredentialSubject structure
+    /// * `bbs_secret` - BBS secret
+    /// * `bbs_private_key` - BBS private key
+    /// * `credential_revocation_did` - revocation list DID (or `None` if no revocation is used)
+    /// * `credential_revocation_id` - index in revocation list (or `None` if no revocation is used)
+    /// * `exp_date` - expiration date, string, e.g. "1722-12-03T14:23:42.120Z" (or `None` if no expiration date is used)
+    /// * `subject_did` - subject did for self issued credential
+    ///
+    /// # Returns
+    /// * credential as JSON serialized [`BbsCredential`](https://docs.rs/vade_evan_bbs/*/vade_evan_bbs/struct.BbsCredential.html)
+    pub async fn create_self_issued_credential(
         &mut self,
         schema_did: &str,
         credential_subject_str: &str,
@@ -423,22 +2056,102 @@ impl<'a> Credential<'a> {
             });
         }
 
-        let schema: CredentialSchema = self.get_did_document(schema_did).await?;
+        let schema = self.get_schema(schema_did).await?;
 
-        let payload = OfferCredentialPayload {
-            draft_credential: schema.to_draft_credential(CredentialDraftOptions {
+        let payload = OfferCredentialPayloadBuilder::new(schema.to_draft_credential(
+            CredentialDraftOptions {
                 issuer_did: issuer.to_owned(),
                 id: None,
                 issuance_date: None,
                 valid_until,
-            }),
-            credential_status_type: match credential_revocation_did.is_some() {
-                true => LdProofVcDetailOptionsCredentialStatusType::RevocationList2021Status,
-                false => LdProofVcDetailOptionsCredentialStatusType::None,
             },
-            required_reveal_statements,
-        };
+        ))
+        .credential_status_included(credential_revocation_did.is_some())
+        .required_reveal_statements(required_reveal_statements)
+        .build()?;
+
+        self.issue_offered_credential(
+            payload,
+            &credential_values_str,
+            &issuer_public_key,
+            bbs_secret,
+            bbs_private_key,
+            credential_status,
+        )
+        .await
+    }
+
+    /// Re-issues a credential with the same claims, subject and revocation status, but a new
+    /// validity window, e.g. for holders of credentials that are about to expire.
+    ///
+    /// # Arguments
+    /// * `old_credential` - credential to refresh in seralized string format
+    /// * `issuer_did` - DID of the issuer, its key "#bbs-key-1" is used to sign the credential
+    /// * `issuer_key` - BBS private key of the issuer
+    /// * `bbs_secret` - master secret of the holder
+    /// * `new_valid_until` - new expiration date, e.g. "2024-12-03T14:23:42.120Z"
+    ///
+    /// # Returns
+    /// * credential as JSON serialized [`BbsCredential`](https://docs.rs/vade_evan_bbs/*/vade_evan_bbs/struct.BbsCredential.html)
+    pub async fn refresh_credential(
+        &mut self,
+        old_credential: &str,
+        issuer_did: &str,
+        issuer_key: &str,
+        bbs_secret: &str,
+        new_valid_until: &str,
+    ) -> Result<String, CredentialError> {
+        fail_if_not_a_did(issuer_did, "issuer_did")?;
+        let mut draft_credential: Value = serde_json::from_str(old_credential)?;
+        let old_proof = draft_credential
+            .as_object_mut()
+            .and_then(|credential| credential.remove("proof"))
+            .ok_or(CredentialError::MissingProof)?;
+        draft_credential["issuer"] = Value::from(issuer_did);
+        draft_credential["issuanceDate"] =
+            Value::from(Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true));
+        draft_credential["validUntil"] = Value::from(new_valid_until);
+        fail_on_invalid_timestamps(&draft_credential)?;
+
+        let credential_status: Option<CredentialStatus> =
+            match draft_credential.get("credentialStatus") {
+                None | Some(Value::Null) => None,
+                Some(credential_status) => Some(serde_json::from_value(credential_status.clone())?),
+            };
+        let required_reveal_statements: Vec<u32> =
+            serde_json::from_value(old_proof["requiredRevealStatements"].clone())?;
+        let draft_credential: UnsignedBbsCredential = serde_json::from_value(draft_credential)?;
+        let credential_values_str = serde_json::to_string(&draft_credential.credential_subject.data)?;
+        let issuer_public_key = self.get_issuer_public_key(issuer_did, "#bbs-key-1").await?;
+
+        let payload = OfferCredentialPayloadBuilder::new(draft_credential)
+            .credential_status_included(credential_status.is_some())
+            .required_reveal_statements(required_reveal_statements)
+            .build()?;
+
+        self.issue_offered_credential(
+            payload,
+            &credential_values_str,
+            &issuer_public_key,
+            bbs_secret,
+            issuer_key,
+            credential_status,
+        )
+        .await
+    }
 
+    /// Runs offer, request, issuance and finishing of a credential for a given offer payload,
+    /// holding both the issuer key and the master secret of the holder.
+    async fn issue_offered_credential(
+        &mut self,
+        payload: OfferCredentialPayload,
+        credential_values_str: &str,
+        issuer_public_key: &str,
+        bbs_secret: &str,
+        bbs_private_key: &str,
+        credential_status: Option<CredentialStatus>,
+    ) -> Result<String, CredentialError> {
+        let schema_did = payload.draft_credential.credential_schema.id.clone();
         let offer_str = self
             .vade_evan
             .vc_zkp_create_credential_offer(
@@ -452,11 +2165,11 @@ impl<'a> Credential<'a> {
         // Create credential request
         let request_str = self
             .create_credential_request(
-                &issuer_public_key,
-                &bbs_secret,
-                &credential_values_str,
+                issuer_public_key,
+                bbs_secret,
+                credential_values_str,
                 &offer_str,
-                schema_did,
+                &schema_did,
             )
             .await?;
         let (request, blinding_key): (BbsCredentialRequest, String) =
@@ -465,7 +2178,7 @@ impl<'a> Credential<'a> {
         // Issue credentials
         let payload = IssueCredentialPayload {
             issuer_public_key_id: "#bbs-key-1".to_string(),
-            issuer_public_key: issuer_public_key.clone(),
+            issuer_public_key: issuer_public_key.to_string(),
             issuer_secret_key: bbs_private_key.to_string(),
             credential_request: request.clone(),
             credential_status,
@@ -485,7 +2198,7 @@ impl<'a> Credential<'a> {
         let payload_finish = FinishCredentialPayload {
             credential,
             master_secret: bbs_secret.to_string(),
-            issuer_public_key: issuer_public_key.clone(),
+            issuer_public_key: issuer_public_key.to_string(),
             blinding: blinding_key,
         };
         let payload_finish_str = serde_json::to_string(&payload_finish)?;
@@ -508,9 +2221,9 @@ impl<'a> Credential<'a> {
             .did_resolve(did)
             .await
             .map_err(|err| CredentialError::VadeEvanError(err.to_string()))?;
-        let did_result_value: DidDocumentResult<T> = serde_json::from_str(&did_result_str)?;
 
-        Ok(did_result_value.did_document)
+        parse_did_document(did, &did_result_str)
+            .map_err(|err| CredentialError::VadeEvanError(err.to_string()))
     }
 
     /// Resolve a issuer did, get the did document and extract the public key out of the
@@ -528,51 +2241,201 @@ impl<'a> Credential<'a> {
         verification_method_id: &str,
     ) -> Result<String, CredentialError> {
         fail_if_not_a_did(issuer_did, "issuer_did")?;
-        let did_document: IdentityDidDocument = self.get_did_document(issuer_did).await?;
+        let cache = self.vade_evan.get_issuer_key_cache();
+        if let Some(key) = cache.get(issuer_did, verification_method_id, None) {
+            return Ok(key);
+        }
+        let did_document = self.get_identity_did_document(issuer_did).await?;
+        let public_key = get_public_key_from_did_document(did_document, verification_method_id)?;
+        self.vade_evan.get_issuer_key_cache().insert(
+            issuer_did,
+            verification_method_id,
+            None,
+            &public_key,
+        );
 
-        let mut public_key: &str = "";
-        let verification_methods = did_document
-            .verification_method
-            .ok_or("no verification method found")
-            .map_err(|err| CredentialError::PublicKeyParsingError(err.to_string()))?;
-        for method in verification_methods.iter() {
-            if method.id == verification_method_id {
-                public_key = &method.public_key_jwk.x;
-                break;
+        Ok(public_key)
+    }
+
+    /// Resolves issuer DIDs and caches the public keys of their verification methods, so first
+    /// verifications of their credentials do not have to wait for DID resolution. Keys are cached
+    /// for each proof purpose their verification method is authorized for.
+    ///
+    /// DIDs are resolved one after another, not concurrently, as resolution needs exclusive
+    /// access to the resolver plugins. A DID that cannot be resolved within 10 seconds or at all
+    /// does not keep the remaining ones from being cached, see `warm_cache_with_timeout`. Has no
+    /// effect if the issuer key cache is disabled.
+    ///
+    /// # Arguments
+    /// * `issuer_dids` - DIDs of issuers to cache keys for
+    pub async fn warm_cache(&mut self, issuer_dids: &[&str]) -> Result<(), CredentialError> {
+        self.warm_cache_with_timeout(issuer_dids, DEFAULT_WARM_CACHE_TIMEOUT)
+            .await
+    }
+
+    /// Like `warm_cache`, but resolution of each DID is given up after `timeout`. As DIDs are
+    /// resolved one after another, warming up the cache takes at most `timeout` per DID. DIDs
+    /// that failed or timed out are listed in the returned error.
+    ///
+    /// # Arguments
+    /// * `issuer_dids` - DIDs of issuers to cache keys for
+    /// * `timeout` - maximum time to wait for the resolution of a single DID
+    pub async fn warm_cache_with_timeout(
+        &mut self,
+        issuer_dids: &[&str],
+        timeout: Duration,
+    ) -> Result<(), CredentialError> {
+        let mut failures: Vec<String> = vec![];
+        for issuer_did in issuer_dids {
+            let caching = Box::pin(self.cache_issuer_keys(issuer_did));
+            match select(caching, Delay::new(timeout)).await {
+                Either::Left((Ok(()), _)) => {}
+                Either::Left((Err(err), _)) => failures.push(format!("{}: {}", issuer_did, err)),
+                Either::Right(_) => failures.push(format!(
+                    "{}: resolution timed out after {}ms",
+                    issuer_did,
+                    timeout.as_millis()
+                )),
             }
         }
 
-        if public_key == "" {
-            return Err(CredentialError::InvalidVerificationMethod(format!(
-                "no public key found for verification id {}",
-                &verification_method_id
-            )));
+        if !failures.is_empty() {
+            return Err(CredentialError::CacheWarmUpFailed(failures.join(", ")));
+        }
+
+        Ok(())
+    }
+
+    async fn cache_issuer_keys(&mut self, issuer_did: &str) -> Result<(), CredentialError> {
+        let did_document = self.get_identity_did_document(issuer_did).await?;
+        let keys = get_cacheable_issuer_keys(&did_document)?;
+        let cache = self.vade_evan.get_issuer_key_cache();
+        for (method_id, proof_purpose, public_key) in keys {
+            cache.insert(issuer_did, &method_id, proof_purpose, &public_key);
+        }
+
+        Ok(())
+    }
+
+    async fn get_identity_did_document(
+        &mut self,
+        did: &str,
+    ) -> Result<IdentityDidDocument, CredentialError> {
+        fail_if_not_a_did(did, "did for did document")?;
+        let did_document = self
+            .vade_evan
+            .did_resolve_typed(did)
+            .await
+            .map_err(|err| CredentialError::VadeEvanError(err.to_string()))?;
+        fail_on_duplicate_verification_method_ids(&did_document)?;
+
+        Ok(did_document)
+    }
+
+    /// Resolves a schema DID and ensures, that it points to a credential schema, so invalid
+    /// schema DIDs are reported before building credentials from them.
+    ///
+    /// # Arguments
+    /// * `schema_did` - DID of the schema to resolve
+    ///
+    /// # Returns
+    /// * `CredentialSchema` - the resolved schema
+    pub async fn get_schema(
+        &mut self,
+        schema_did: &str,
+    ) -> Result<CredentialSchema, CredentialError> {
+        let schema: Value = self.get_did_document(schema_did).await?;
+        let is_schema = schema["type"].as_str() == Some(SCHEMA_TYPE)
+            && SCHEMA_REQUIRED_FIELDS
+                .iter()
+                .all(|field| !schema[field].is_null());
+        if !is_schema {
+            return Err(CredentialError::NotASchema {
+                did: schema_did.to_string(),
+            });
+        }
+
+        Ok(serde_json::from_value(schema)?)
+    }
+
+    /// Resolves the DID document of an issuer and ensures, that it contains at least one
+    /// verification method, that can be used to sign credentials.
+    async fn check_issuer(&mut self, issuer_did: &str) -> Result<(), CredentialError> {
+        let did_document = self
+            .get_identity_did_document(issuer_did)
+            .await
+            .map_err(|err| {
+                CredentialError::InvalidIssuer(format!(
+                    "could not resolve issuer DID {}; {}",
+                    issuer_did, err
+                ))
+            })?;
+
+        fail_if_no_signing_key(&did_document)
+    }
+
+    /// Gets the public key of an issuer and ensures, that the verification method is authorized
+    /// for given proof purpose in the issuer's DID document.
+    pub async fn get_issuer_public_key_for_purpose(
+        &mut self,
+        issuer_did: &str,
+        verification_method_id: &str,
+        proof_purpose: &str,
+    ) -> Result<String, CredentialError> {
+        fail_if_not_a_did(issuer_did, "issuer_did")?;
+        let cache = self.vade_evan.get_issuer_key_cache();
+        if let Some(key) = cache.get(issuer_did, verification_method_id, Some(proof_purpose)) {
+            return Ok(key);
         }
+        let did_document = self.get_identity_did_document(issuer_did).await?;
+        check_verification_method_purpose(&did_document, verification_method_id, proof_purpose)?;
+        let public_key = get_public_key_from_did_document(did_document, verification_method_id)?;
+        self.vade_evan.get_issuer_key_cache().insert(
+            issuer_did,
+            verification_method_id,
+            Some(proof_purpose),
+            &public_key,
+        );
+
+        Ok(public_key)
+    }
+
+    async fn verify_signature_with_key(
+        &self,
+        credential: &BbsCredential,
+        did_doc_nquads: &[String],
+        issuer_public_key: &str,
+        master_secret: Option<&str>,
+    ) -> Result<(), CredentialError> {
+        let master_secret = master_secret.map(get_master_secret_message).transpose()?;
+        let public_key_generator = get_public_key_generator(
+            issuer_public_key,
+            did_doc_nquads.len() + master_secret.iter().count(),
+        )?;
 
-        Ok(public_key.to_string())
+        self.verify_proof_signature(
+            &credential.proof.signature,
+            did_doc_nquads,
+            master_secret,
+            &public_key_generator,
+        )
+        .await
     }
 
     async fn verify_proof_signature(
         &self,
         signature: &str,
-        did_doc_nquads: &Vec<String>,
-        master_secret: &str,
+        did_doc_nquads: &[String],
+        master_secret: Option<SignatureMessage>,
         pk: &PublicKey,
     ) -> Result<(), CredentialError> {
-        let mut signature_messages: Vec<SignatureMessage> = Vec::new();
-        let master_secret_message: SignatureMessage =
-            SignatureMessage::from(base64::decode(master_secret)?.into_boxed_slice());
-        signature_messages.insert(0, master_secret_message);
-        let mut i = 1;
-        for message in did_doc_nquads {
-            signature_messages.insert(i, SignatureMessage::hash(message));
-            i += 1;
-        }
-        let decoded_proof = base64::decode(signature)?;
-        let signature = panic::catch_unwind(|| Signature::from(decoded_proof.into_boxed_slice()))
-            .map_err(|_| {
-            CredentialError::BbsValidationError("Error parsing signature".to_string())
-        })?;
+        // the master secret is signed as first message, if the credential is bound to one
+        let signature_messages: Vec<SignatureMessage> = master_secret
+            .into_iter()
+            .chain(did_doc_nquads.iter().map(SignatureMessage::hash))
+            .collect();
+        let decoded_proof = decode_base64(signature)?;
+        let signature = parse_signature(&decoded_proof)?;
         let is_valid = signature
             .verify(&signature_messages, &pk)
             .map_err(|err| CredentialError::BbsValidationError(err.to_string()))?;
@@ -594,12 +2457,40 @@ mod tests {
     cfg_if::cfg_if! {
         if #[cfg(feature = "did-sidetree")] {
             use anyhow::Result;
-            use vade_evan_bbs::{BbsCredential, BbsCredentialOffer};
+            use vade_evan_bbs::{BbsCredential, BbsCredentialOffer, UnsignedBbsCredential};
             use crate::{VadeEvan, DEFAULT_SIGNER, DEFAULT_TARGET};
             use vade_sidetree::datatypes::DidCreateResponse;
-            use vade_evan_bbs::RevocationListCredential;
-            use crate::helpers::datatypes::DidDocumentResult;
-            use super::{Credential, CredentialError};
+            use vade_evan_bbs::{CredentialStatus, RevocationListCredential};
+            use crate::helpers::datatypes::{DidDocumentResult, IdentityDidDocument};
+            use super::{
+                check_verification_method_purpose,
+                decode_base64,
+                decode_encoded_list,
+                get_cacheable_issuer_keys,
+                get_list_encoding,
+                ListEncoding,
+                fail_if_no_signing_key,
+                fail_on_context_mismatch,
+                fail_on_subject_mismatch,
+                fail_on_unexpected_property,
+                fail_on_duplicate_verification_method_ids,
+                get_nquads_without_proof,
+                get_verified_nquads,
+                fail_on_invalid_timestamps,
+                is_id_signed,
+                get_first_unset_index,
+                get_public_key_from_did_document,
+                get_revoked_indices,
+                parse_signature,
+                validate_public_key,
+                Credential,
+                CredentialError,
+                CredentialOfferOptions,
+                OfferCredentialPayloadBuilder,
+                RevocationState,
+                Signature,
+                SignatureMessage,
+            };
 
             const CREDENTIAL_ACTIVE: &str = r###"{
                 "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
@@ -677,6 +2568,45 @@ mod tests {
                     }
                 }
             }"###;
+            // well-formed signature, that has been created for the messages of another credential
+            const CREDENTIAL_MISMATCHING_PROOF_SIGNATURE: &str = r###"{
+                "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
+                "type": [
+                    "VerifiableCredential"
+                ],
+                "proof": {
+                    "type": "BbsBlsSignature2020",
+                    "created": "2023-02-01T14:08:17.000Z",
+                    "signature": "lqKrWCzOaeL4qRRyhN4555I5/A/TmKQ9iJUvA+34pwNfh4rBLFxKlLwJK5dfuQjrDZ+0EWSK8X+e7Jv9cWjOZ+v/t3lgT3nFczMtfPjgFe4a3iWKCRUi1HM6h1+c6HY+C0j0QOB606TTXe2EInb+WQ==",
+                    "proofPurpose": "assertionMethod",
+                    "verificationMethod": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA#bbs-key-1",
+                    "credentialMessageCount": 13,
+                    "requiredRevealStatements": []
+                },
+                "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+                "@context": [
+                    "https://www.w3.org/2018/credentials/v1",
+                    "https://schema.org/",
+                    "https://w3id.org/vc-revocation-list-2020/v1"
+                ],
+                "issuanceDate": "2023-02-01T14:08:09.849Z",
+                "credentialSchema": {
+                    "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
+                    "type": "EvanVCSchema"
+                },
+                "credentialStatus": {
+                    "id": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA#4",
+                    "type": "RevocationList2020Status",
+                    "revocationListIndex": "4",
+                    "revocationListCredential": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA"
+                },
+                "credentialSubject": {
+                    "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+                    "data": {
+                        "bio": "biography"
+                    }
+                }
+            }"###;
             const CREDENTIAL_REVOKED: &str = r###"{
                 "id": "uuid:19b1e481-8743-4c27-8934-45d682714ccc",
                 "type": [
@@ -717,6 +2647,34 @@ mod tests {
             }"###;
             const ISSUER_DID: &str = "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA";
             const PUBLIC_KEY: &str = "qWZ7EGhzYsSlBq4mLhNal6cHXBD88ZfncdbEWQoue6SaAbZ7k56IxsjcvuXD6LGYDgMgtjTHnBraaMRiwJVBJenXgOT8nto7ZUTO/TvCXwtyPMzGrLM5JNJdEaPP4QJN";
+            const HOLDER_PRIVATE_KEY: &str =
+                "dfcdcb6d5d09411ae9cbe1b0fd9751ba8803dd4b276d5bf9488ae4ede2669106";
+            const ISSUER_DID_DOCUMENT: &str = r###"{
+                "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+                "verificationMethod": [
+                    {
+                        "id": "#bbs-key-1",
+                        "type": "Bls12381G2Key2020",
+                        "publicKeyJwk": {
+                            "kty": "EC",
+                            "crv": "BLS12381_G2",
+                            "x": "qWZ7EGhzYsSlBq4mLhNal6cHXBD88ZfncdbEWQoue6SaAbZ7k56IxsjcvuXD6LGYDgMgtjTHnBraaMRiwJVBJenXgOT8nto7ZUTO/TvCXwtyPMzGrLM5JNJdEaPP4QJN"
+                        }
+                    },
+                    {
+                        "id": "#key-agreement-1",
+                        "type": "JsonWebKey2020",
+                        "publicKeyJwk": {
+                            "kty": "EC",
+                            "crv": "secp256k1",
+                            "x": "4qW3h0_EjOYXnnnVKlpRRdz6AYxv5-OHVMq0YzDHGC8",
+                            "y": "6eVK1pzGRORVJmXfG1JeyCYNjEM3ThPZQPGvCuO3Y6g"
+                        }
+                    }
+                ],
+                "assertionMethod": ["#bbs-key-1"],
+                "keyAgreement": ["#key-agreement-1"]
+            }"###;
             const MASTER_SECRET: &str = "QyRmu33oIQFNW+dSI5wex3u858Ra7yx5O1tsxJgQvu8=";
             const SCHEMA_DID: &str = "did:evan:EiACv4q04NPkNRXQzQHOEMa3r1p_uINgX75VYP2gaK5ADw";
             const VERIFICATION_METHOD_ID: &str = "#bbs-key-1";
@@ -725,173 +2683,1477 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn helper_cannot_create_proof_request_with_invalid_did() -> Result<()> {
+    async fn helper_cannot_create_proof_request_with_invalid_did() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        let result = credential
+            .create_credential_offer("not a did", false, ISSUER_DID, true, "[1]")
+            .await;
+
+        assert!(result.is_err());
+        match result {
+            Ok(_) => assert!(false, "expected error but got result"),
+            Err(error) => assert_eq!(
+                error.to_string(),
+                r#"value "not a did" given for "schema_did is not a DID""#.to_string()
+            ),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(
+        feature = "did-sidetree",
+        not(all(feature = "c-lib", feature = "target-c-sdk"))
+    ))]
+    async fn helper_can_create_credential_offer() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        let offer_str = credential
+            .create_credential_offer(SCHEMA_DID, false, ISSUER_DID, true, "[1]")
+            .await?;
+
+        let offer_obj: BbsCredentialOffer = serde_json::from_str(&offer_str)?;
+        assert_eq!(
+            offer_obj
+                .ld_proof_vc_detail
+                .options
+                .required_reveal_statements,
+            vec![1]
+        );
+        assert_eq!(offer_obj.ld_proof_vc_detail.credential.issuer, ISSUER_DID);
+        assert!(!offer_obj.nonce.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(
+        feature = "did-sidetree",
+        not(all(feature = "c-lib", feature = "target-c-sdk"))
+    ))]
+    async fn helper_can_validate_credential_offer() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        let offer_str = credential
+            .create_credential_offer(SCHEMA_DID, false, ISSUER_DID, true, "[1]")
+            .await?;
+        credential.validate_offer(&offer_str).await?;
+
+        let mut tampered_offer: BbsCredentialOffer = serde_json::from_str(&offer_str)?;
+        tampered_offer
+            .ld_proof_vc_detail
+            .credential
+            .credential_subject
+            .data
+            .insert("extra".to_string(), String::new());
+        match credential
+            .validate_offer(&serde_json::to_string(&tampered_offer)?)
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for offer with claim not in schema"),
+            Err(CredentialError::InvalidCredentialOffer(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+        vade_evan.helper_validate_offer(&offer_str).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(
+        feature = "did-sidetree",
+        not(all(feature = "c-lib", feature = "target-c-sdk"))
+    ))]
+    async fn helper_rejects_schema_did_without_schema() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        let schema = credential.get_schema(SCHEMA_DID).await?;
+        assert_eq!(schema.id, SCHEMA_DID);
+
+        match credential
+            .create_credential_offer(ISSUER_DID, false, ISSUER_DID, true, "[1]")
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for DID without schema"),
+            Err(CredentialError::NotASchema { did }) => assert_eq!(did, ISSUER_DID),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(
+        feature = "did-sidetree",
+        not(all(feature = "c-lib", feature = "target-c-sdk"))
+    ))]
+    async fn helper_can_create_credential_offer_with_validated_issuer() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        let options = CredentialOfferOptions {
+            validate_issuer: true,
+            ..Default::default()
+        };
+        let offer_str = credential
+            .create_credential_offer_with_options(
+                SCHEMA_DID,
+                false,
+                ISSUER_DID,
+                true,
+                "[1]",
+                &options,
+            )
+            .await?;
+
+        let offer_obj: BbsCredentialOffer = serde_json::from_str(&offer_str)?;
+        assert_eq!(offer_obj.ld_proof_vc_detail.credential.issuer, ISSUER_DID);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(
+        feature = "did-sidetree",
+        not(all(feature = "c-lib", feature = "target-c-sdk"))
+    ))]
+    async fn helper_can_create_credential_offer_with_checked_terms() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        let options = CredentialOfferOptions {
+            check_undefined_terms: true,
+            ..Default::default()
+        };
+        let offer_str = credential
+            .create_credential_offer_with_options(
+                SCHEMA_DID,
+                false,
+                ISSUER_DID,
+                true,
+                "[1]",
+                &options,
+            )
+            .await?;
+
+        let offer_obj: BbsCredentialOffer = serde_json::from_str(&offer_str)?;
+        assert_eq!(offer_obj.ld_proof_vc_detail.credential.issuer, ISSUER_DID);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(
+        feature = "did-sidetree",
+        not(all(feature = "c-lib", feature = "target-c-sdk"))
+    ))]
+    async fn helper_rejects_credential_offer_for_unresolvable_issuer() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+        let unknown_issuer_did = "did:evan:EiBrPL8Yif5NWHOzbKvyh1PX1wKVlWvIa6nTG1v8PXytvgfoobar";
+
+        let options = CredentialOfferOptions {
+            validate_issuer: true,
+            ..Default::default()
+        };
+        let result = credential
+            .create_credential_offer_with_options(
+                SCHEMA_DID,
+                false,
+                unknown_issuer_did,
+                true,
+                "[1]",
+                &options,
+            )
+            .await;
+
+        match result {
+            Ok(_) => assert!(false, "offer for unresolvable issuer should not be created"),
+            Err(CredentialError::InvalidIssuer(message)) => {
+                assert!(message.contains(unknown_issuer_did))
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn accepts_credential_with_expected_subject() -> Result<()> {
+        let credential: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+
+        fail_on_subject_mismatch(&credential.credential_subject, Some(ISSUER_DID))?;
+        fail_on_subject_mismatch(&credential.credential_subject, None)?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_credential_with_other_subject() -> Result<()> {
+        let credential: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let other_subject = "did:evan:EiAOD3RUcQrRXNZIR8BIEXuGvixcUj667_5fdeX-Sp3PpA";
+
+        match fail_on_subject_mismatch(&credential.credential_subject, Some(other_subject)) {
+            Ok(_) => assert!(false, "subject mismatch should be detected"),
+            Err(CredentialError::SubjectMismatch(message)) => {
+                assert!(message.contains(other_subject))
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_credential_without_subject_id_if_subject_is_expected() -> Result<()> {
+        let mut credential: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential.credential_subject.id = None;
+
+        fail_on_subject_mismatch(&credential.credential_subject, None)?;
+        match fail_on_subject_mismatch(&credential.credential_subject, Some(ISSUER_DID)) {
+            Ok(_) => assert!(false, "missing subject id should be detected"),
+            Err(CredentialError::SubjectMismatch(message)) => {
+                assert!(message.contains("no subject id"))
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn accepts_credential_with_consistent_context() -> Result<()> {
+        let mut credential: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+
+        fail_on_context_mismatch(&credential)?;
+
+        // a credentialStatus of null does not require the revocation list context
+        credential["@context"] = serde_json::json!(["https://www.w3.org/2018/credentials/v1"]);
+        credential["credentialStatus"] = serde_json::Value::Null;
+        fail_on_context_mismatch(&credential)?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_credential_with_inconsistent_context() -> Result<()> {
+        let mut credential: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential["@context"] = serde_json::json!([
+            "https://www.w3.org/2018/credentials/v1",
+            "https://schema.org/"
+        ]);
+
+        match fail_on_context_mismatch(&credential) {
+            Ok(_) => assert!(false, "missing revocation list context should be detected"),
+            Err(CredentialError::ContextMismatch(message)) => {
+                assert!(message.contains("credentialStatus"))
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        credential["@context"] = serde_json::json!([
+            "https://schema.org/",
+            "https://www.w3.org/2018/credentials/v1",
+            "https://w3id.org/vc-revocation-list-2020/v1"
+        ]);
+
+        match fail_on_context_mismatch(&credential) {
+            Ok(_) => assert!(false, "wrong context order should be detected"),
+            Err(CredentialError::ContextMismatch(message)) => {
+                assert!(message.contains("first entry"))
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_issuer_without_signing_key() -> Result<()> {
+        let mut did_document: IdentityDidDocument = serde_json::from_str(ISSUER_DID_DOCUMENT)?;
+        fail_if_no_signing_key(&did_document)?;
+
+        did_document.assertion_method = None;
+
+        match fail_if_no_signing_key(&did_document) {
+            Ok(_) => assert!(false, "issuer without signing key should be rejected"),
+            Err(CredentialError::InvalidIssuer(message)) => {
+                assert!(message.contains(ISSUER_DID))
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn gets_cacheable_keys_of_own_verification_methods_only() -> Result<()> {
+        let mut did_document: serde_json::Value = serde_json::from_str(ISSUER_DID_DOCUMENT)?;
+        did_document["verificationMethod"][1]["id"] =
+            serde_json::Value::from("did:evan:other#bbs-key-1");
+
+        let keys = get_cacheable_issuer_keys(&serde_json::from_value(did_document.clone())?)?;
+        assert_eq!(
+            keys,
+            vec![
+                ("#bbs-key-1".to_string(), None, PUBLIC_KEY.to_string()),
+                ("#bbs-key-1".to_string(), Some("assertionMethod"), PUBLIC_KEY.to_string()),
+            ]
+        );
+
+        // same checks as uncached lookups
+        did_document["verificationMethod"][1]["id"] =
+            serde_json::Value::from(format!("{}#bbs-key-1", ISSUER_DID));
+        match get_cacheable_issuer_keys(&serde_json::from_value(did_document)?) {
+            Ok(_) => assert!(false, "duplicate verification method ids should be rejected"),
+            Err(CredentialError::InvalidDidDocument(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(all(
+        feature = "did-sidetree",
+        not(all(feature = "c-lib", feature = "target-c-sdk"))
+    ))]
+    async fn helper_can_get_nonce_from_credential_offer() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+        let offer_str = credential
+            .create_credential_offer(SCHEMA_DID, false, ISSUER_DID, true, "[1]")
+            .await?;
+        let offer_obj: BbsCredentialOffer = serde_json::from_str(&offer_str)?;
+
+        let nonce = Credential::get_offer_nonce(&offer_str)?;
+
+        assert!(!nonce.is_empty());
+        assert_eq!(nonce, offer_obj.nonce);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_malformed_credential_offer_when_getting_nonce() {
+        match Credential::get_offer_nonce(r#"{ "nonce": 42 }"#) {
+            Ok(_) => assert!(false, "malformed offer should be rejected"),
+            Err(CredentialError::InvalidCredentialOffer(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_create_credential_request() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: "test",
+            signer: "remote|http://127.0.0.1:7070/key/sign",
+        })?;
+        let credential_offer = vade_evan
+            .helper_create_credential_offer(SCHEMA_DID, false, ISSUER_DID, true, "[1]")
+            .await?;
+
+        let bbs_secret = r#"OASkVMA8q6b3qJuabvgaN9K1mKoqptCv4SCNvRmnWuI="#;
+        let credential_values = r#"{
+        "email": "value@x.com"
+    }"#;
+        let issuer_pub_key = r#"jCv7l26izalfcsFe6j/IqtVlDolo2Y3lNld7xOG63GjSNHBVWrvZQe2O859q9JeVEV4yXtfYofGQSWrMVfgH5ySbuHpQj4fSgLu4xXyFgMidUO1sIe0NHRcXpOorP01o"#;
+
+        let credential_request = vade_evan
+            .helper_create_credential_request(
+                issuer_pub_key,
+                bbs_secret,
+                credential_values,
+                &credential_offer,
+                SCHEMA_DID,
+            )
+            .await?;
+
+        assert!(credential_request.contains("blindSignatureContext"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn can_get_issuer_pub_key() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let mut credential = Credential::new(&mut vade_evan)?;
+        let pub_key = credential
+            .get_issuer_public_key(ISSUER_DID, VERIFICATION_METHOD_ID)
+            .await?;
+
+        assert_eq!(pub_key, PUBLIC_KEY);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn will_throw_when_pub_key_not_found() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let mut credential = Credential::new(&mut vade_evan)?;
+        let pub_key = credential
+            .get_issuer_public_key(ISSUER_DID, "#random-id")
+            .await;
+
+        match pub_key {
+            Ok(_) => assert!(false, "pub key should not be there"),
+            Err(_) => assert!(true, "pub key not found"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_properties_not_allowed_by_schema() -> Result<()> {
+        let mut schema = serde_json::json!({
+            "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
+            "type": "EvanVCSchema",
+            "properties": { "bio": { "type": "string" } },
+            "additionalProperties": false
+        });
+        let credential_value: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        fail_on_unexpected_property(&schema, &credential_value)?;
+
+        let mut credential_with_extra_property = credential_value.clone();
+        credential_with_extra_property["credentialSubject"]["data"]["age"] =
+            serde_json::Value::from("42");
+        match fail_on_unexpected_property(&schema, &credential_with_extra_property) {
+            Ok(_) => assert!(false, "got no error for property not in schema"),
+            Err(CredentialError::UnexpectedProperty(property)) => assert_eq!(property, "age"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        schema["additionalProperties"] = serde_json::Value::from(true);
+        fail_on_unexpected_property(&schema, &credential_with_extra_property)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_verify_valid_credential() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        // verify the credential issuer
+        credential
+            .verify_credential(CREDENTIAL_ACTIVE, MASTER_SECRET, None)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_verifies_all_proofs_of_a_credential() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        let active: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let invalid: serde_json::Value = serde_json::from_str(CREDENTIAL_INVALID_PROOF_SIGNATURE)?;
+        let mut credential_with_proofs = active.clone();
+        credential_with_proofs["proof"] =
+            serde_json::Value::Array(vec![active["proof"].clone(), active["proof"].clone()]);
+        credential
+            .verify_credential(&credential_with_proofs.to_string(), MASTER_SECRET, None)
+            .await?;
+
+        credential_with_proofs["proof"] =
+            serde_json::Value::Array(vec![active["proof"].clone(), invalid["proof"].clone()]);
+        match credential
+            .verify_credential(&credential_with_proofs.to_string(), MASTER_SECRET, None)
+            .await
+        {
+            Ok(_) => assert!(false, "credential with invalid second proof should fail"),
+            Err(CredentialError::BbsValidationError(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        credential_with_proofs["proof"] = serde_json::Value::Array(vec![]);
+        match credential
+            .verify_credential(&credential_with_proofs.to_string(), MASTER_SECRET, None)
+            .await
+        {
+            Ok(_) => assert!(false, "credential without proofs should fail"),
+            Err(CredentialError::MissingProof) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_verify_and_parse_valid_credential() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let verified_credential = vade_evan
+            .helper_verify_credential_and_parse(CREDENTIAL_ACTIVE, MASTER_SECRET)
+            .await?;
+
+        assert_eq!(verified_credential.issuer, ISSUER_DID);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_does_not_parse_credential_with_invalid_proof_signature() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        match credential
+            .verify_credential_and_parse(CREDENTIAL_INVALID_PROOF_SIGNATURE, MASTER_SECRET)
+            .await
+        {
+            Ok(_) => assert!(false, "credential should have been detected as invalid"),
+            Err(CredentialError::BbsValidationError(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_rejects_credentials_with_unsupported_proof_type() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        let mut credential_parsed: serde_json::Value = serde_json::from_str(&CREDENTIAL_ACTIVE)?;
+        credential_parsed["proof"]["type"] = serde_json::Value::from("Ed25519Signature2018");
+        let credential_with_other_proof = serde_json::to_string(&credential_parsed)?;
+
+        match credential
+            .verify_credential(&credential_with_other_proof, MASTER_SECRET, None)
+            .await
+        {
+            Ok(_) => assert!(false, "credential should have been rejected"),
+            Err(CredentialError::UnsupportedProofType(proof_type)) => {
+                assert_eq!(proof_type, "Ed25519Signature2018");
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_verify_credential_with_any_candidate_key() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        let matching_method_id = credential
+            .verify_credential_any_key(
+                CREDENTIAL_ACTIVE,
+                &["#unknown-key", "#key-agreement-1", VERIFICATION_METHOD_ID],
+                MASTER_SECRET,
+            )
+            .await?;
+
+        assert_eq!(matching_method_id, VERIFICATION_METHOD_ID);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_rejects_credential_if_no_candidate_key_matches() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        match credential
+            .verify_credential_any_key(
+                CREDENTIAL_MISMATCHING_PROOF_SIGNATURE,
+                &["#unknown-key", VERIFICATION_METHOD_ID],
+                MASTER_SECRET,
+            )
+            .await
+        {
+            Ok(_) => assert!(false, "credential should not match any key"),
+            Err(CredentialError::NoMatchingIssuerKey(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn can_redact_credential_and_presentation_for_logging() -> Result<()> {
+        let credential: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let presentation = serde_json::json!({
+            "id": "uuid:5a2f9e8c-4f31-4e09-8b51-2b4f1c8ed5a4",
+            "type": ["VerifiablePresentation"],
+            "verifiableCredential": [serde_json::from_str::<serde_json::Value>(CREDENTIAL_ACTIVE)?],
+            "proof": {
+                "type": "EcdsaPublicKeySecp256k1",
+                "jws": "eyJ0eXAiOiJKV1QiLCJhbGciOiJFUzI1NkstUiJ9.eyJpYXQiOjE2NzUyNjA"
+            }
+        });
+
+        for to_redact in [CREDENTIAL_ACTIVE.to_string(), presentation.to_string()] {
+            let redacted = Credential::redact_for_logging(&to_redact)?;
+
+            assert!(!redacted.contains("biography"));
+            assert!(!redacted.contains(&credential.proof.signature));
+            assert!(!redacted.contains("eyJ0eXAiOiJKV1QiLCJhbGciOiJFUzI1NkstUiJ9"));
+            assert!(redacted.contains(r#""bio":"***""#));
+            assert!(redacted.contains(&credential.issuer));
+            assert!(redacted.contains(&credential.credential_schema.id));
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_validate_issuer_key() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        vade_evan
+            .helper_validate_issuer_key(ISSUER_DID, VERIFICATION_METHOD_ID, 13)
+            .await?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_public_key_for_mismatched_message_count() {
+        assert!(validate_public_key(PUBLIC_KEY, 13).is_ok());
+
+        for message_count in [0, 1] {
+            match validate_public_key(PUBLIC_KEY, message_count) {
+                Ok(_) => assert!(false, "message count {} should be rejected", message_count),
+                Err(CredentialError::PublicKeyParsingError(message)) => {
+                    assert!(message.contains("does not cover master secret"))
+                }
+                Err(err) => assert!(false, "got unexpected error: {}", err),
+            };
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_truncated_public_key() -> Result<()> {
+        let truncated_key = base64::encode(&base64::decode(PUBLIC_KEY)?[..48]);
+
+        match validate_public_key(&truncated_key, 13) {
+            Ok(_) => assert!(false, "truncated key should be rejected"),
+            Err(CredentialError::PublicKeyParsingError(message)) => {
+                assert_eq!(message, "public key has 48 bytes, expected 96")
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_verify_derived_proofs() -> Result<()> {
+        use bbs::prelude::{
+            HiddenMessage,
+            Issuer,
+            ProofChallenge,
+            ProofMessage,
+            ProofNonce,
+            Prover,
+            Verifier,
+        };
+
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+
+        // sign nquads of active credential with a new key and derive a proof revealing all nquads
+        let credential_parsed: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let nquads = get_verified_nquads(&credential_parsed, CREDENTIAL_ACTIVE, false).await?;
+        let (public_key, secret_key) = Issuer::new_short_keys(None);
+        let public_key_generator = public_key
+            .to_public_key(nquads.len() + 1)
+            .map_err(|err| anyhow::anyhow!("{}", err))?;
+        let master_secret =
+            SignatureMessage::from(decode_base64(MASTER_SECRET)?.into_boxed_slice());
+        let messages: Vec<SignatureMessage> = std::iter::once(master_secret)
+            .chain(nquads.iter().map(SignatureMessage::hash))
+            .collect();
+        let signature = Signature::new(&messages, &secret_key, &public_key_generator)
+            .map_err(|err| anyhow::anyhow!("{}", err))?;
+        let revealed_indices: Vec<usize> = (1..messages.len()).collect();
+        let proof_request = Verifier::new_proof_request(&revealed_indices, &public_key_generator)
+            .map_err(|err| anyhow::anyhow!("{}", err))?;
+        let proof_messages: Vec<ProofMessage> = messages
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(index, message)| match index {
+                0 => ProofMessage::Hidden(HiddenMessage::ProofSpecificBlinding(message)),
+                _ => ProofMessage::Revealed(message),
+            })
+            .collect();
+        let nonce_bytes = vec![7u8; 32];
+        let nonce = ProofNonce::from(nonce_bytes.clone().into_boxed_slice());
+        let proof_of_knowledge =
+            Prover::commit_signature_pok(&proof_request, &proof_messages, &signature)
+                .map_err(|err| anyhow::anyhow!("{}", err))?;
+        let mut challenge_bytes = proof_of_knowledge.to_bytes();
+        challenge_bytes.extend_from_slice(&nonce.to_bytes_uncompressed_form()[..]);
+        let challenge = ProofChallenge::hash(&challenge_bytes);
+        let proof = Prover::generate_signature_pok(proof_of_knowledge, &challenge)
+            .map_err(|err| anyhow::anyhow!("{}", err))?;
+
+        let mut credential_value: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential_value["proof"] = serde_json::json!({
+            "type": "BbsBlsSignatureProof2020",
+            "created": credential_value["proof"]["created"],
+            "proofPurpose": "assertionMethod",
+            "verificationMethod": credential_value["proof"]["verificationMethod"],
+            "credentialMessageCount": messages.len(),
+            "nonce": base64::encode(&nonce_bytes),
+            "proof": base64::encode(&proof.to_bytes_compressed_form()),
+        });
+        let derived_credential = credential_value.to_string();
+        let public_key = base64::encode(&public_key.to_bytes_compressed_form()[..]);
+
+        credential
+            .verify_derived_credential_with_key(&derived_credential, &public_key)
+            .await?;
+
+        // derived proofs are verified as well when passed to credential verification, this one
+        // has not been created with the issuer's key
+        match credential.verify_credential(&derived_credential, MASTER_SECRET, None).await {
+            Ok(_) => assert!(false, "derived proof of another key should be invalid"),
+            Err(CredentialError::BbsValidationError(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        let mut tampered_credential = credential_value.clone();
+        tampered_credential["credentialSubject"]["data"]["bio"] =
+            serde_json::Value::from("tampered biography");
+        match credential
+            .verify_derived_credential_with_key(&tampered_credential.to_string(), &public_key)
+            .await
+        {
+            Ok(_) => assert!(false, "tampered credential should be invalid"),
+            Err(CredentialError::BbsValidationError(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        // proofs hiding attributes need the indices from their proof request
+        let mut partially_revealed = credential_value.clone();
+        partially_revealed["proof"]["credentialMessageCount"] =
+            serde_json::Value::from(messages.len() + 1);
+        match credential
+            .verify_derived_credential_with_key(&partially_revealed.to_string(), &public_key)
+            .await
+        {
+            Ok(_) => assert!(false, "proof hiding attributes should not be verified"),
+            Err(CredentialError::DerivedProofOutsidePresentation(proof_type)) => {
+                assert_eq!(proof_type, "BbsBlsSignatureProof2020");
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        vade_evan
+            .helper_verify_derived_credential_with_key(&derived_credential, &public_key)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_convert_credential_to_jwt_vp_and_back() -> Result<()> {
+        use alloy_signer::Signer;
+
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let credential = Credential::new(&mut vade_evan)?;
+        let holder_address = HOLDER_PRIVATE_KEY
+            .parse::<alloy_signer_wallet::LocalWallet>()?
+            .address()
+            .to_string();
+
+        let jwt_vp = credential.to_jwt_vp(CREDENTIAL_ACTIVE, HOLDER_PRIVATE_KEY).await?;
+        assert_eq!(jwt_vp.split('.').count(), 3);
+
+        let extracted_credential = credential.from_jwt_vp(&jwt_vp, &holder_address)?;
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&extracted_credential)?,
+            serde_json::from_str::<serde_json::Value>(CREDENTIAL_ACTIVE)?,
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_rejects_jwt_vp_signed_by_other_holder() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let credential = Credential::new(&mut vade_evan)?;
+        let jwt_vp = credential.to_jwt_vp(CREDENTIAL_ACTIVE, HOLDER_PRIVATE_KEY).await?;
+
+        match credential.from_jwt_vp(&jwt_vp, "0x0d87204c3957d73b68ae28d0af961d3c72403906") {
+            Ok(_) => assert!(false, "JWT VP should have been rejected"),
+            Err(CredentialError::InvalidJwtPresentation(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_verify_credential_with_given_key() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let credential = Credential::new(&mut vade_evan)?;
+
+        credential
+            .verify_credential_with_key(CREDENTIAL_ACTIVE, PUBLIC_KEY, MASTER_SECRET)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_get_message_index_of_attribute() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let credential = Credential::new(&mut vade_evan)?;
+
+        let index = credential.attribute_index(CREDENTIAL_ACTIVE, "bio").await?;
+
+        let credential_parsed: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let nquads = get_verified_nquads(&credential_parsed, CREDENTIAL_ACTIVE, false).await?;
+        // first message is the master secret
+        assert!(nquads[index - 1].contains("<http://schema.org/bio>"));
+        assert!(nquads[index - 1].contains("biography"));
+
+        match credential.attribute_index(CREDENTIAL_ACTIVE, "unknown").await {
+            Ok(_) => assert!(false, "got index for unknown attribute"),
+            Err(CredentialError::UnknownAttribute(name)) => assert_eq!(name, "unknown"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+        assert_eq!(
+            vade_evan.helper_attribute_index(CREDENTIAL_ACTIVE, "bio").await?,
+            index,
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_map_attributes_to_messages() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let credential = Credential::new(&mut vade_evan)?;
+
+        let mapping = credential.attribute_mapping(CREDENTIAL_ACTIVE).await?;
+
+        assert_eq!(mapping.len(), 1);
+        assert_eq!(mapping[0].attribute, "bio");
+        assert_eq!(
+            mapping[0].message_index,
+            credential.attribute_index(CREDENTIAL_ACTIVE, "bio").await?,
+        );
+        assert!(mapping[0].nquad.contains("<http://schema.org/bio>"));
+        assert_eq!(
+            vade_evan.helper_attribute_mapping(CREDENTIAL_ACTIVE).await?,
+            mapping,
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_verify_credential_with_given_issuer_document() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let credential = Credential::new(&mut vade_evan)?;
+        let mut did_document: IdentityDidDocument = serde_json::from_str(ISSUER_DID_DOCUMENT)?;
+
+        credential
+            .verify_credential_with_document(
+                CREDENTIAL_ACTIVE,
+                &did_document,
+                VERIFICATION_METHOD_ID,
+                MASTER_SECRET,
+            )
+            .await?;
+
+        did_document.id = "did:evan:EiBrPL8Yif5NWHOzbKvyh1PX1wKVlWvIa6nTG1v8PXytvg".to_string();
+        match credential
+            .verify_credential_with_document(
+                CREDENTIAL_ACTIVE,
+                &did_document,
+                VERIFICATION_METHOD_ID,
+                MASTER_SECRET,
+            )
+            .await
+        {
+            Ok(_) => assert!(false, "document of other DID should not be accepted"),
+            Err(CredentialError::InvalidIssuer(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        let did_document: IdentityDidDocument = serde_json::from_str(ISSUER_DID_DOCUMENT)?;
+        vade_evan
+            .helper_verify_credential_with_document(
+                CREDENTIAL_ACTIVE,
+                &did_document,
+                VERIFICATION_METHOD_ID,
+                MASTER_SECRET,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_verifies_pre_normalized_credential_like_full_credential() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let credential = Credential::new(&mut vade_evan)?;
+
+        for credential_str in [CREDENTIAL_ACTIVE, CREDENTIAL_INVALID_PROOF_SIGNATURE] {
+            let credential_parsed: BbsCredential = serde_json::from_str(credential_str)?;
+            let nquads = get_verified_nquads(&credential_parsed, credential_str, false).await?;
+
+            let full_result = credential
+                .verify_credential_with_key(credential_str, PUBLIC_KEY, MASTER_SECRET)
+                .await;
+            let pre_normalized_result = credential
+                .verify_credential_with_nquads(
+                    &nquads,
+                    &credential_parsed.proof,
+                    PUBLIC_KEY,
+                    MASTER_SECRET,
+                )
+                .await;
+
+            assert_eq!(
+                full_result.map_err(|err| err.to_string()),
+                pre_normalized_result.map_err(|err| err.to_string()),
+            );
+        }
+
+        let credential_parsed: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let nquads = get_verified_nquads(&credential_parsed, CREDENTIAL_ACTIVE, false).await?;
+        vade_evan
+            .helper_verify_credential_with_nquads(
+                &nquads,
+                &credential_parsed.proof,
+                PUBLIC_KEY,
+                MASTER_SECRET,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn can_decode_standard_and_url_safe_base64() -> Result<()> {
+        let url_safe_key = base64::encode_config(base64::decode(PUBLIC_KEY)?, base64::URL_SAFE);
+        assert_ne!(url_safe_key, PUBLIC_KEY);
+
+        assert_eq!(decode_base64(PUBLIC_KEY)?, decode_base64(&url_safe_key)?);
+        assert!(decode_base64("not base64!").is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_verify_url_safe_encoded_key_and_signature() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let credential = Credential::new(&mut vade_evan)?;
+
+        let mut credential_parsed: BbsCredential = serde_json::from_str(&CREDENTIAL_ACTIVE)?;
+        credential_parsed.proof.signature = base64::encode_config(
+            base64::decode(&credential_parsed.proof.signature)?,
+            base64::URL_SAFE,
+        );
+        let credential_with_url_safe_signature = serde_json::to_string(&credential_parsed)?;
+        let url_safe_key = base64::encode_config(base64::decode(PUBLIC_KEY)?, base64::URL_SAFE);
+
+        credential
+            .verify_credential_with_key(
+                &credential_with_url_safe_signature,
+                &url_safe_key,
+                MASTER_SECRET,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_pre_verifies_credential_with_given_key() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let credential = Credential::new(&mut vade_evan)?;
+
+        let mut credential_value: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential_value["validUntil"] = serde_json::json!("2023-13-01 14:08:09");
+        match credential
+            .verify_credential_with_key(&credential_value.to_string(), PUBLIC_KEY, MASTER_SECRET)
+            .await
+        {
+            Ok(_) => assert!(false, "malformed validUntil should not be accepted"),
+            Err(CredentialError::InvalidTimestamp { field }) => assert_eq!(field, "validUntil"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        let mut credential_value: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential_value["proof"]["type"] = serde_json::json!("Ed25519Signature2018");
+        match credential
+            .verify_credential_with_key(&credential_value.to_string(), PUBLIC_KEY, MASTER_SECRET)
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for unsupported proof type"),
+            Err(CredentialError::UnsupportedProofType(proof_type)) => {
+                assert_eq!(proof_type, "Ed25519Signature2018")
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+        match credential
+            .verify_unbound_credential_with_key(&credential_value.to_string(), PUBLIC_KEY)
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for unsupported proof type"),
+            Err(CredentialError::UnsupportedProofType(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_verify_compressed_and_uncompressed_signatures() -> Result<()> {
         let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
             target: DEFAULT_TARGET,
             signer: DEFAULT_SIGNER,
         })?;
-        let mut credential = Credential::new(&mut vade_evan)?;
 
-        let result = credential
-            .create_credential_offer("not a did", false, ISSUER_DID, true, "[1]")
-            .await;
+        let credential = Credential::new(&mut vade_evan)?;
 
-        assert!(result.is_err());
-        match result {
-            Ok(_) => assert!(false, "expected error but got result"),
-            Err(error) => assert_eq!(
-                error.to_string(),
-                r#"value "not a did" given for "schema_did is not a DID""#.to_string()
-            ),
-        };
+        let mut credential_parsed: BbsCredential = serde_json::from_str(&CREDENTIAL_ACTIVE)?;
+        let compressed_signature = base64::decode(&credential_parsed.proof.signature)?;
+        let uncompressed_signature =
+            parse_signature(&compressed_signature)?.to_bytes_uncompressed_form();
+        assert_ne!(compressed_signature.len(), uncompressed_signature.len());
+
+        for signature in [compressed_signature, uncompressed_signature.to_vec()] {
+            credential_parsed.proof.signature = base64::encode(signature);
+            credential
+                .verify_credential_with_key(
+                    &serde_json::to_string(&credential_parsed)?,
+                    PUBLIC_KEY,
+                    MASTER_SECRET,
+                )
+                .await?;
+        }
 
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_signature_with_unexpected_length() {
+        match parse_signature(&[0u8; 100]) {
+            Ok(_) => assert!(false, "signature should not have been parsed"),
+            Err(err) => assert_eq!(
+                err.to_string(),
+                "an error has occurred during bbs signature validation: Error parsing signature, unexpected length 100",
+            ),
+        }
+    }
+
     #[tokio::test]
-    #[cfg(all(
-        feature = "did-sidetree",
-        not(all(feature = "c-lib", feature = "target-c-sdk"))
-    ))]
-    async fn helper_can_create_credential_offer() -> Result<()> {
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_rejects_invalid_signature_with_given_key() -> Result<()> {
         let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
             target: DEFAULT_TARGET,
             signer: DEFAULT_SIGNER,
         })?;
-        let mut credential = Credential::new(&mut vade_evan)?;
 
-        let offer_str = credential
-            .create_credential_offer(SCHEMA_DID, false, ISSUER_DID, true, "[1]")
-            .await?;
+        let credential = Credential::new(&mut vade_evan)?;
 
-        let offer_obj: BbsCredentialOffer = serde_json::from_str(&offer_str)?;
-        assert_eq!(
-            offer_obj
-                .ld_proof_vc_detail
-                .options
-                .required_reveal_statements,
-            vec![1]
-        );
-        assert_eq!(offer_obj.ld_proof_vc_detail.credential.issuer, ISSUER_DID);
-        assert!(!offer_obj.nonce.is_empty());
+        match credential
+            .verify_credential_with_key(
+                CREDENTIAL_INVALID_PROOF_SIGNATURE,
+                PUBLIC_KEY,
+                MASTER_SECRET,
+            )
+            .await
+        {
+            Ok(_) => assert!(false, "credential should have been detected as invalid"),
+            Err(CredentialError::BbsValidationError(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
 
         Ok(())
     }
 
     #[tokio::test]
     #[cfg(feature = "did-sidetree")]
-    async fn helper_can_create_credential_request() -> Result<()> {
+    async fn helper_rejects_credentials_with_invalid_message_count() -> Result<()> {
         let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
-            target: "test",
-            signer: "remote|http://127.0.0.1:7070/key/sign",
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
         })?;
-        let credential_offer = vade_evan
-            .helper_create_credential_offer(SCHEMA_DID, false, ISSUER_DID, true, "[1]")
-            .await?;
 
-        let bbs_secret = r#"OASkVMA8q6b3qJuabvgaN9K1mKoqptCv4SCNvRmnWuI="#;
-        let credential_values = r#"{
-        "email": "value@x.com"
-    }"#;
-        let issuer_pub_key = r#"jCv7l26izalfcsFe6j/IqtVlDolo2Y3lNld7xOG63GjSNHBVWrvZQe2O859q9JeVEV4yXtfYofGQSWrMVfgH5ySbuHpQj4fSgLu4xXyFgMidUO1sIe0NHRcXpOorP01o"#;
+        let mut credential = Credential::new(&mut vade_evan)?;
 
-        let credential_request = vade_evan
-            .helper_create_credential_request(
-                issuer_pub_key,
-                bbs_secret,
-                credential_values,
-                &credential_offer,
-                SCHEMA_DID,
-            )
-            .await?;
+        let mut credential_parsed: BbsCredential = serde_json::from_str(&CREDENTIAL_ACTIVE)?;
+        credential_parsed.proof.credential_message_count = 3;
+        let credential_with_invalid_msg_count = serde_json::to_string(&credential_parsed)?;
 
-        assert!(credential_request.contains("blindSignatureContext"));
+        match credential
+            .verify_credential(&credential_with_invalid_msg_count, MASTER_SECRET, None)
+            .await
+        {
+            Ok(_) => assert!(false, "credential should have been detected as revoked"),
+            Err(CredentialError::MessageCountMismatch(got, expected)) => {
+                assert_eq!(3, got);
+                assert_eq!(13, expected);
+                assert!(true, "credential revoked as expected")
+            }
+            _ => assert!(false, "revocation check failed with unexpected error"),
+        };
 
         Ok(())
     }
 
     #[tokio::test]
     #[cfg(feature = "did-sidetree")]
-    async fn can_get_issuer_pub_key() -> Result<()> {
+    async fn helper_detects_tampered_credential_id() -> Result<()> {
         let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
             target: DEFAULT_TARGET,
             signer: DEFAULT_SIGNER,
         })?;
+        let credential = Credential::new(&mut vade_evan)?;
 
-        let mut credential = Credential::new(&mut vade_evan)?;
-        let pub_key = credential
-            .get_issuer_public_key(ISSUER_DID, VERIFICATION_METHOD_ID)
-            .await?;
+        let credential_parsed: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let nquads = get_verified_nquads(&credential_parsed, CREDENTIAL_ACTIVE, false).await?;
+        assert!(is_id_signed(&nquads, &credential_parsed.id));
 
-        assert_eq!(pub_key, PUBLIC_KEY);
+        let mut credential_value: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential_value["id"] =
+            serde_json::Value::from("uuid:00000000-0000-0000-0000-000000000000");
+        match credential
+            .verify_credential_with_key(&credential_value.to_string(), PUBLIC_KEY, MASTER_SECRET)
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for tampered credential id"),
+            Err(CredentialError::BbsValidationError(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
 
         Ok(())
     }
 
     #[tokio::test]
     #[cfg(feature = "did-sidetree")]
-    async fn will_throw_when_pub_key_not_found() -> Result<()> {
+    async fn helper_rejects_unsigned_credential_id_if_enabled() -> Result<()> {
         let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
             target: DEFAULT_TARGET,
             signer: DEFAULT_SIGNER,
         })?;
-
         let mut credential = Credential::new(&mut vade_evan)?;
-        let pub_key = credential
-            .get_issuer_public_key(ISSUER_DID, "#random-id")
-            .await;
 
-        match pub_key {
-            Ok(_) => assert!(false, "pub key should not be there"),
-            Err(_) => assert!(true, "pub key not found"),
-        }
+        // relative ids are no IRIs, so they are not part of the signed nquads
+        let mut credential_value: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential_value["id"] = serde_json::Value::from("credential-1");
+        let credential_str = credential_value.to_string();
+        let credential_parsed: BbsCredential = serde_json::from_str(&credential_str)?;
+        let nquads = get_nquads_without_proof(&credential_str).await?;
+        assert!(!is_id_signed(&nquads, &credential_parsed.id));
+
+        match credential
+            .verify_credential_with_key(&credential_str, PUBLIC_KEY, MASTER_SECRET)
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for altered credential"),
+            Err(CredentialError::UnsignedCredentialId(_)) => {
+                assert!(false, "signed id check should be disabled by default")
+            }
+            Err(_) => {}
+        };
+
+        credential.set_signed_id_check(true);
+        match credential
+            .verify_credential_with_key(&credential_str, PUBLIC_KEY, MASTER_SECRET)
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for unsigned credential id"),
+            Err(CredentialError::UnsignedCredentialId(id)) => assert_eq!(id, "credential-1"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        // helpers created after enabling the check on the instance inherit it
+        vade_evan.set_signed_id_check(true);
+        let credential = Credential::new(&mut vade_evan)?;
+        match credential
+            .verify_credential_with_key(&credential_str, PUBLIC_KEY, MASTER_SECRET)
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for unsigned credential id"),
+            Err(CredentialError::UnsignedCredentialId(id)) => assert_eq!(id, "credential-1"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    fn helper_inherits_schema_property_check_from_vade_evan() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        assert!(!Credential::new(&mut vade_evan)?.check_schema_properties);
+
+        vade_evan.set_schema_property_check(true);
+        assert!(Credential::new(&mut vade_evan)?.check_schema_properties);
 
         Ok(())
     }
 
     #[tokio::test]
     #[cfg(feature = "did-sidetree")]
-    async fn helper_can_verify_valid_credential() -> Result<()> {
+    async fn helper_verifies_credentials_with_and_without_master_secret() -> Result<()> {
+        use bbs::prelude::Issuer;
+
         let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
             target: DEFAULT_TARGET,
             signer: DEFAULT_SIGNER,
         })?;
+        let credential = Credential::new(&mut vade_evan)?;
 
-        let mut credential = Credential::new(&mut vade_evan)?;
+        // sign nquads of active credential again, without master secret as first message
+        let credential_parsed: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let nquads = get_verified_nquads(&credential_parsed, CREDENTIAL_ACTIVE, false).await?;
+        let (public_key, secret_key) = Issuer::new_short_keys(None);
+        let public_key_generator = public_key
+            .to_public_key(nquads.len())
+            .map_err(|err| anyhow::anyhow!("{}", err))?;
+        let messages: Vec<SignatureMessage> = nquads.iter().map(SignatureMessage::hash).collect();
+        let signature = Signature::new(&messages, &secret_key, &public_key_generator)
+            .map_err(|err| anyhow::anyhow!("{}", err))?;
+        let mut credential_value: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential_value["proof"]["signature"] =
+            serde_json::Value::from(base64::encode(&signature.to_bytes_compressed_form()[..]));
+        credential_value["proof"]["credentialMessageCount"] = serde_json::Value::from(nquads.len());
+        let credential_without_master_secret = credential_value.to_string();
+        let public_key = base64::encode(&public_key.to_bytes_compressed_form()[..]);
+
+        credential
+            .verify_unbound_credential_with_key(&credential_without_master_secret, &public_key)
+            .await?;
+        match credential
+            .verify_credential_with_key(
+                &credential_without_master_secret,
+                &public_key,
+                MASTER_SECRET,
+            )
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for credential without master secret"),
+            Err(CredentialError::MessageCountMismatch(count, expected)) => {
+                assert_eq!(count, nquads.len());
+                assert_eq!(expected, nquads.len() + 1);
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
 
-        // verify the credential issuer
         credential
-            .verify_credential(CREDENTIAL_ACTIVE, MASTER_SECRET)
+            .verify_credential_with_key(CREDENTIAL_ACTIVE, PUBLIC_KEY, MASTER_SECRET)
             .await?;
+        match credential
+            .verify_unbound_credential_with_key(CREDENTIAL_ACTIVE, PUBLIC_KEY)
+            .await
+        {
+            Ok(_) => assert!(false, "got no error for credential bound to master secret"),
+            Err(CredentialError::MessageCountMismatch(13, 12)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
 
         Ok(())
     }
 
     #[tokio::test]
     #[cfg(feature = "did-sidetree")]
-    async fn helper_rejects_credentials_with_invalid_message_count() -> Result<()> {
+    async fn helper_can_check_revocation_status() -> Result<()> {
         let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
             target: DEFAULT_TARGET,
             signer: DEFAULT_SIGNER,
         })?;
-
         let mut credential = Credential::new(&mut vade_evan)?;
 
-        let mut credential_parsed: BbsCredential = serde_json::from_str(&CREDENTIAL_ACTIVE)?;
-        credential_parsed.proof.credential_message_count = 3;
-        let credential_with_invalid_msg_count = serde_json::to_string(&credential_parsed)?;
+        assert_eq!(
+            credential.revocation_status(CREDENTIAL_ACTIVE).await?,
+            RevocationState::Active,
+        );
+        assert_eq!(
+            credential.revocation_status(CREDENTIAL_REVOKED).await?,
+            RevocationState::Revoked,
+        );
 
-        match credential
-            .verify_credential(&credential_with_invalid_msg_count, MASTER_SECRET)
-            .await
-        {
-            Ok(_) => assert!(false, "credential should have been detected as revoked"),
-            Err(CredentialError::MessageCountMismatch(got, expected)) => {
-                assert_eq!(3, got);
-                assert_eq!(13, expected);
-                assert!(true, "credential revoked as expected")
-            }
-            _ => assert!(false, "revocation check failed with unexpected error"),
+        let mut credential_without_status: serde_json::Value =
+            serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential_without_status["credentialStatus"] = serde_json::Value::Null;
+        assert_eq!(
+            credential
+                .revocation_status(&credential_without_status.to_string())
+                .await?,
+            RevocationState::Active,
+        );
+        assert_eq!(
+            vade_evan.helper_revocation_status(CREDENTIAL_REVOKED).await?,
+            RevocationState::Revoked,
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn rejects_revocation_list_index_out_of_range() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut credential = Credential::new(&mut vade_evan)?;
+        let credential_value: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let mut credential_status: CredentialStatus =
+            serde_json::from_value(credential_value["credentialStatus"].clone())?;
+        let revocation_list: serde_json::Value = credential
+            .get_did_document(&credential_status.revocation_list_credential)
+            .await?;
+
+        credential_status.revocation_list_index = usize::MAX.to_string();
+        match is_revoked(&credential_status, &revocation_list) {
+            Ok(_) => assert!(false, "got no error for index out of range"),
+            Err(CredentialError::RevocationListInvalid(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
         };
 
         Ok(())
@@ -910,7 +4172,7 @@ mod tests {
         let mut credential = Credential::new(&mut vade_evan)?;
 
         match credential
-            .verify_credential(CREDENTIAL_REVOKED, MASTER_SECRET)
+            .verify_credential(CREDENTIAL_REVOKED, MASTER_SECRET, None)
             .await
         {
             Ok(_) => assert!(false, "credential should have been detected as revoked"),
@@ -964,7 +4226,7 @@ mod tests {
         assert!(did_update_result.is_ok());
 
         // check is credential is not revoked
-        match is_revoked(credential_status, &revocation_list)? {
+        match is_revoked(credential_status, &serde_json::to_value(&revocation_list)?)? {
             false => assert!(true, "credential is active and not revoked as expected"),
             true => assert!(
                 false,
@@ -985,27 +4247,96 @@ mod tests {
             .helper_revoke_credential(
                 &serde_json::to_string(&credential)?,
                 &serde_json::to_string(&update_key)?,
-                "dfcdcb6d5d09411ae9cbe1b0fd9751ba8803dd4b276d5bf9488ae4ede2669106",
+                "dfcdcb6d5d09411ae9cbe1b0fd9751ba8803dd4b276d5bf9488ae4ede2669106",
+            )
+            .await;
+        assert!(revoke_result.is_ok());
+
+        //fetch revocation list after revocation
+        let did_result_str = vade_evan
+            .did_resolve(credential_status.revocation_list_credential.as_str())
+            .await?;
+        let did_result_value: DidDocumentResult<RevocationListCredential> =
+            serde_json::from_str(&did_result_str)?;
+        revocation_list = did_result_value.did_document;
+
+        // verify credential
+        match is_revoked(credential_status, &serde_json::to_value(&revocation_list)?)? {
+            false => assert!(false, "credential should have been detected as revoked"),
+            true => assert!(true, "credential revoked as expected"),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_revoke_many_credentials() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: "test",
+            signer: "local",
+        })?;
+        // create did and replace its document with a copy of a revocation list
+        let did_create_result = vade_evan
+            .helper_did_create(None, None, None, None, None)
+            .await?;
+        let did_create_result: DidCreateResponse = serde_json::from_str(&did_create_result)?;
+        let credential: BbsCredential = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        let mut credential_status = credential.credential_status.ok_or_else(|| {
+            CredentialError::InvalidCredentialStatus(
+                "Error in parsing credential_status".to_string(),
+            )
+        })?;
+        let did_result_str = vade_evan
+            .did_resolve(&credential_status.revocation_list_credential)
+            .await?;
+        let did_result_value: DidDocumentResult<RevocationListCredential> =
+            serde_json::from_str(&did_result_str)?;
+        let mut revocation_list = did_result_value.did_document;
+        revocation_list.id = did_create_result.did.did_document.id.clone();
+        vade_evan
+            .helper_did_update(
+                &revocation_list.id,
+                "ReplaceDidDoc",
+                &serde_json::to_string(&did_create_result.update_key)?,
+                &serde_json::to_string(&revocation_list)?,
+            )
+            .await?;
+
+        let mut update_key = did_create_result.update_key.clone();
+        let nonce = update_key
+            .nonce
+            .unwrap_or_else(|| "0".to_string())
+            .parse::<u32>()?;
+        update_key.nonce = Some((nonce + 1).to_string());
+        let revoked_indices = [7, 8, 20];
+        vade_evan
+            .helper_revoke_credentials(
+                &revocation_list.id,
+                &revoked_indices,
+                &serde_json::to_string(&update_key)?,
             )
-            .await;
-        assert!(revoke_result.is_ok());
-
-        //fetch revocation list after revocation
-        let did_result_str = vade_evan
-            .did_resolve(credential_status.revocation_list_credential.as_str())
             .await?;
-        let did_result_value: DidDocumentResult<RevocationListCredential> =
-            serde_json::from_str(&did_result_str)?;
-        revocation_list = did_result_value.did_document;
 
-        // verify credential
-        match is_revoked(credential_status, &revocation_list)? {
-            false => assert!(false, "credential should have been detected as revoked"),
-            true => assert!(true, "credential revoked as expected"),
-        };
+        let mut credential_helper = Credential::new(&mut vade_evan)?;
+        let updated_revocation_list: RevocationListCredential =
+            credential_helper.get_did_document(&revocation_list.id).await?;
+        for index in 0..24 {
+            credential_status.revocation_list_index = index.to_string();
+            let was_revoked =
+                is_revoked(&credential_status, &serde_json::to_value(&revocation_list)?)?;
+            assert_eq!(
+                is_revoked(
+                    &credential_status,
+                    &serde_json::to_value(&updated_revocation_list)?
+                )?,
+                was_revoked || revoked_indices.contains(&index),
+            );
+        }
 
         Ok(())
     }
+
     #[tokio::test]
     #[cfg(feature = "did-sidetree")]
     async fn helper_can_detect_a_credential_with_an_invalid_proof_signature() -> Result<()> {
@@ -1016,12 +4347,24 @@ mod tests {
 
         let mut credential = Credential::new(&mut vade_evan)?;
 
+        // malformed signatures are rejected before resolving the issuer
+        match credential
+            .verify_credential(CREDENTIAL_INVALID_PROOF_SIGNATURE, MASTER_SECRET, None)
+            .await
+        {
+            Ok(_) => assert!(false, "credential should have been detected as invalid"),
+            Err(CredentialError::BbsValidationError(message)) => {
+                assert_eq!(message, "Error parsing signature, unexpected length 6");
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
         // verify the credential issuer
         match credential
-            .verify_credential(CREDENTIAL_INVALID_PROOF_SIGNATURE, MASTER_SECRET)
+            .verify_credential(CREDENTIAL_MISMATCHING_PROOF_SIGNATURE, MASTER_SECRET, None)
             .await
         {
-            Ok(_) => assert!(false, "credential should have been detected as revoked"),
+            Ok(_) => assert!(false, "credential should have been detected as invalid"),
             Err(credential_error) => {
                 assert_eq!(
                     credential_error.to_string(),
@@ -1081,6 +4424,64 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_refresh_credential() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: "test",
+            signer: "remote|http://127.0.0.1:7070/key/sign",
+        })?;
+        let credential_subject_str = r#"{
+            "data": {
+                "email": "value@x.com"
+            }
+        }"#;
+        let subject_id = "did:evan:EiAOD3RUcQrRXNZIR8BIEXuGvixcUj667_5fdeX-Sp3PpA";
+        let bbs_secret = "GRsdzRB0pf/8MKP/ZBOM2BEV1A8DIDfmLh8T3b1hPKc=";
+        let bbs_private_key = "WWTZW8pkz35UnvsUCEsof2CJmNHaJQ/X+B5xjWcHr/I=";
+        let schema_did = "did:evan:EiACv4q04NPkNRXQzQHOEMa3r1p_uINgX75VYP2gaK5ADw";
+        let new_valid_until = "2031-12-03T14:23:42.120Z";
+
+        let mut credential = Credential::new(&mut vade_evan)?;
+        let old_credential = credential
+            .create_self_issued_credential(
+                schema_did,
+                credential_subject_str,
+                bbs_secret,
+                bbs_private_key,
+                None,
+                None,
+                Some("2030-12-03T14:23:42.120Z"),
+                subject_id,
+                "[1]",
+            )
+            .await?;
+
+        let refreshed_credential = vade_evan
+            .helper_refresh_credential(
+                &old_credential,
+                subject_id,
+                bbs_private_key,
+                bbs_secret,
+                new_valid_until,
+            )
+            .await?;
+
+        let old_credential: serde_json::Value = serde_json::from_str(&old_credential)?;
+        let refreshed_credential: serde_json::Value = serde_json::from_str(&refreshed_credential)?;
+        assert_eq!(refreshed_credential["validUntil"], new_valid_until);
+        assert_eq!(
+            refreshed_credential["credentialSubject"],
+            old_credential["credentialSubject"],
+        );
+        assert_eq!(
+            refreshed_credential["proof"]["requiredRevealStatements"],
+            serde_json::json!([1]),
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[cfg(feature = "did-sidetree")]
     async fn helper_can_create_self_issued_credential_without_credential_status() -> Result<()> {
@@ -1131,4 +4532,416 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[cfg(feature = "did-sidetree")]
+    async fn helper_can_verify_non_revocable_credential() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: "test",
+            signer: "remote|http://127.0.0.1:7070/key/sign",
+        })?;
+        let credential_subject_str = r#"{
+            "data": {
+                "email": "value@x.com"
+            }
+        }"#;
+        let subject_id = "did:evan:EiAOD3RUcQrRXNZIR8BIEXuGvixcUj667_5fdeX-Sp3PpA";
+        let bbs_secret = "GRsdzRB0pf/8MKP/ZBOM2BEV1A8DIDfmLh8T3b1hPKc=";
+        let bbs_private_key = "WWTZW8pkz35UnvsUCEsof2CJmNHaJQ/X+B5xjWcHr/I=";
+        let schema_did = "did:evan:EiACv4q04NPkNRXQzQHOEMa3r1p_uINgX75VYP2gaK5ADw";
+
+        let mut credential = Credential::new(&mut vade_evan)?;
+        let revocable_credential_str = credential
+            .create_self_issued_credential(
+                schema_did,
+                credential_subject_str,
+                bbs_secret,
+                bbs_private_key,
+                Some("did:revoc:12345"),
+                Some("1"),
+                None,
+                subject_id,
+                "[1]",
+            )
+            .await?;
+        let non_revocable_credential_str = credential
+            .create_self_issued_credential(
+                schema_did,
+                credential_subject_str,
+                bbs_secret,
+                bbs_private_key,
+                None,
+                None,
+                None,
+                subject_id,
+                "[1]",
+            )
+            .await?;
+        let revocable_credential: BbsCredential = serde_json::from_str(&revocable_credential_str)?;
+        let non_revocable_credential: BbsCredential =
+            serde_json::from_str(&non_revocable_credential_str)?;
+
+        assert!(non_revocable_credential.credential_status.is_none());
+        assert_ne!(
+            non_revocable_credential.proof.credential_message_count,
+            revocable_credential.proof.credential_message_count
+        );
+
+        let issuer_public_key = credential
+            .get_issuer_public_key(subject_id, "#bbs-key-1")
+            .await?;
+        credential
+            .verify_credential_with_key(
+                &non_revocable_credential_str,
+                &issuer_public_key,
+                bbs_secret,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn accepts_verification_method_authorized_for_proof_purpose() -> Result<()> {
+        let did_document: IdentityDidDocument = serde_json::from_str(ISSUER_DID_DOCUMENT)?;
+
+        check_verification_method_purpose(&did_document, "#bbs-key-1", "assertionMethod")?;
+        check_verification_method_purpose(
+            &did_document,
+            &format!("{}#bbs-key-1", ISSUER_DID),
+            "assertionMethod",
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn can_find_public_key_by_absolute_and_relative_id() -> Result<()> {
+        let did_document: IdentityDidDocument = serde_json::from_str(ISSUER_DID_DOCUMENT)?;
+
+        for verification_method_id in [
+            "#bbs-key-1".to_string(),
+            format!("{}#bbs-key-1", ISSUER_DID),
+            format!("{}?versionId=1#bbs-key-1", ISSUER_DID),
+        ] {
+            let public_key =
+                get_public_key_from_did_document(did_document.clone(), &verification_method_id)?;
+            assert_eq!(public_key, PUBLIC_KEY);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_did_document_with_duplicate_verification_method_ids() -> Result<()> {
+        let mut did_document: IdentityDidDocument = serde_json::from_str(ISSUER_DID_DOCUMENT)?;
+        fail_on_duplicate_verification_method_ids(&did_document)?;
+
+        if let Some(verification_methods) = did_document.verification_method.as_mut() {
+            let mut duplicate = verification_methods[1].clone();
+            duplicate.id = format!("{}#bbs-key-1", ISSUER_DID);
+            verification_methods.push(duplicate);
+        }
+
+        match fail_on_duplicate_verification_method_ids(&did_document) {
+            Ok(_) => assert!(false, "duplicate verification method id should be detected"),
+            Err(CredentialError::InvalidDidDocument(message)) => {
+                assert!(message.contains("#bbs-key-1"))
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn does_not_find_public_key_for_unknown_fragment() -> Result<()> {
+        let did_document: IdentityDidDocument = serde_json::from_str(ISSUER_DID_DOCUMENT)?;
+
+        for verification_method_id in [
+            "#bbs-key-2".to_string(),
+            "did:evan:EiBrPL8Yif5NWHOzbKvyh1PX1wKVlWvIa6nTG1v8PXytvg#bbs-key-1".to_string(),
+        ] {
+            match get_public_key_from_did_document(did_document.clone(), &verification_method_id) {
+                Ok(_) => assert!(false, "pub key should not be there"),
+                Err(CredentialError::InvalidVerificationMethod(_)) => {}
+                Err(err) => assert!(false, "got unexpected error: {}", err),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_verification_method_not_authorized_for_proof_purpose() -> Result<()> {
+        let did_document: IdentityDidDocument = serde_json::from_str(ISSUER_DID_DOCUMENT)?;
+
+        match check_verification_method_purpose(
+            &did_document,
+            "#key-agreement-1",
+            "assertionMethod",
+        ) {
+            Ok(_) => assert!(false, "key agreement key should not be accepted for assertions"),
+            Err(CredentialError::UnauthorizedVerificationMethod(id, purpose)) => {
+                assert_eq!(id, format!("{}#key-agreement-1", ISSUER_DID));
+                assert_eq!(purpose, "assertionMethod");
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn finds_first_free_index_in_partially_used_revocation_list() {
+        // indices 0 to 10 and 12 are flagged
+        let decoded_list = [0b1111_1111, 0b0001_0111, 0b0000_0000];
+
+        assert_eq!(get_first_unset_index(&decoded_list), Some(11));
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn finds_no_free_index_in_full_revocation_list() {
+        let decoded_list = [u8::MAX; 16];
+
+        assert_eq!(get_first_unset_index(&decoded_list), None);
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn lists_revoked_indices_of_revocation_list_2020_and_status_list_2021() -> Result<()> {
+        let get_status_list = |list_type: &str, decoded_list: &[u8]| -> Result<serde_json::Value> {
+            let encoded_list = base64::encode_config(gzip(decoded_list)?, base64::URL_SAFE);
+            Ok(serde_json::json!({
+                "credentialSubject": {
+                    "type": list_type,
+                    "encodedList": encoded_list,
+                },
+            }))
+        };
+
+        // indices 0, 3 and 9 are flagged
+        let revocation_list = get_status_list("RevocationList2020", &[0b0000_1001, 0b0000_0010])?;
+        assert_eq!(get_revoked_indices(&revocation_list)?, vec![0, 3, 9]);
+        let status_list = get_status_list("StatusList2021", &[0b1001_0000, 0b0100_0000])?;
+        assert_eq!(get_revoked_indices(&status_list)?, vec![0, 3, 9]);
+
+        let unknown_list = get_status_list("BitstringStatusList", &[0b0000_0001])?;
+        match get_revoked_indices(&unknown_list) {
+            Ok(_) => assert!(false, "got indices for unsupported status list type"),
+            Err(CredentialError::RevocationListInvalid(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[cfg(feature = "did-sidetree")]
+    fn gzip(bytes: &[u8]) -> Result<Vec<u8>> {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(bytes)?;
+
+        Ok(encoder.finish()?)
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn decodes_gzipped_and_raw_revocation_lists() -> Result<()> {
+        let decoded_list = vec![0b0000_1001, 0b0000_0010];
+
+        let gzipped_list = base64::encode_config(gzip(&decoded_list)?, base64::URL_SAFE);
+        assert_eq!(decode_encoded_list(&gzipped_list, ListEncoding::Gzip)?, decoded_list);
+        let raw_list = base64::encode_config(&decoded_list, base64::URL_SAFE);
+        assert_eq!(decode_encoded_list(&raw_list, ListEncoding::Raw)?, decoded_list);
+
+        // encoding is taken from the list's metadata, not guessed from its content
+        let raw_status_list = serde_json::json!({
+            "credentialSubject": {
+                "type": "RevocationList2020",
+                "encoding": "raw",
+                "encodedList": raw_list,
+            },
+        });
+        assert_eq!(get_revoked_indices(&raw_status_list)?, vec![0, 3, 9]);
+        match decode_encoded_list(&raw_list, ListEncoding::Gzip) {
+            Ok(_) => assert!(false, "raw list should not be decoded as gzip"),
+            Err(CredentialError::UnsupportedRevocationEncoding { .. }) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn checks_revocation_in_raw_and_status_list_2021_lists() -> Result<()> {
+        let credential_status_at = |index: u32| -> Result<CredentialStatus> {
+            Ok(serde_json::from_value(serde_json::json!({
+                "id": format!("did:evan:revocation#{}", index),
+                "type": "RevocationList2020Status",
+                "revocationListIndex": index.to_string(),
+                "revocationListCredential": "did:evan:revocation",
+            }))?)
+        };
+        // index 9 is flagged
+        let raw_revocation_list = serde_json::json!({
+            "credentialSubject": {
+                "type": "RevocationList2020",
+                "encoding": "raw",
+                "encodedList": base64::encode_config([0b0000_0000, 0b0000_0010], base64::URL_SAFE),
+            },
+        });
+        let status_list = serde_json::json!({
+            "credentialSubject": {
+                "type": "StatusList2021",
+                "encodedList": base64::encode_config(
+                    gzip(&[0b0000_0000, 0b0100_0000])?,
+                    base64::URL_SAFE,
+                ),
+            },
+        });
+
+        for list in [&raw_revocation_list, &status_list] {
+            assert!(is_revoked(&credential_status_at(9)?, list)?);
+            assert!(!is_revoked(&credential_status_at(8)?, list)?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn detects_status_list_encoding_from_metadata() -> Result<()> {
+        assert_eq!(get_list_encoding("RevocationList2020", None)?, ListEncoding::Gzip);
+        assert_eq!(get_list_encoding("StatusList2021", Some("gzip"))?, ListEncoding::Gzip);
+        assert_eq!(get_list_encoding("StatusList2021", Some("raw"))?, ListEncoding::Raw);
+
+        for (list_type, encoding) in [
+            ("RevocationList2020", Some("zstd")),
+            ("BitstringStatusList", None),
+        ] {
+            match get_list_encoding(list_type, encoding) {
+                Ok(_) => assert!(false, "got encoding for {} {:?}", list_type, encoding),
+                Err(CredentialError::UnsupportedRevocationEncoding { .. }) => {}
+                Err(err) => assert!(false, "got unexpected error: {}", err),
+            };
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_malformed_revocation_lists() {
+        // invalid base64 and a gzip header without valid compressed data
+        let truncated_gzip = base64::encode_config([0x1f, 0x8b, 0x08, 0x00], base64::URL_SAFE);
+        for encoded_list in ["not base64!", truncated_gzip.as_str()] {
+            match decode_encoded_list(encoded_list, ListEncoding::Gzip) {
+                Ok(_) => assert!(false, "got no error for malformed list {}", encoded_list),
+                Err(CredentialError::UnsupportedRevocationEncoding { .. }) => {}
+                Err(err) => assert!(false, "got unexpected error: {}", err),
+            };
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_malformed_valid_until() -> Result<()> {
+        let mut credential: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential["validUntil"] = serde_json::json!("2023-13-01 14:08:09");
+
+        match fail_on_invalid_timestamps(&credential) {
+            Ok(_) => assert!(false, "malformed validUntil should not be accepted"),
+            Err(CredentialError::InvalidTimestamp { field }) => assert_eq!(field, "validUntil"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        credential["validUntil"] = serde_json::json!("2123-02-01T14:08:09.849Z");
+        assert!(fail_on_invalid_timestamps(&credential).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_malformed_issuance_date() -> Result<()> {
+        let mut credential: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential["issuanceDate"] = serde_json::json!("01.02.2023");
+
+        match fail_on_invalid_timestamps(&credential) {
+            Ok(_) => assert!(false, "malformed issuanceDate should not be accepted"),
+            Err(CredentialError::InvalidTimestamp { field }) => assert_eq!(field, "issuanceDate"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[cfg(feature = "did-sidetree")]
+    fn get_draft_credential() -> Result<UnsignedBbsCredential> {
+        let mut credential: serde_json::Value = serde_json::from_str(CREDENTIAL_ACTIVE)?;
+        credential
+            .as_object_mut()
+            .ok_or_else(|| anyhow::anyhow!("credential is not an object"))?
+            .remove("proof");
+
+        Ok(serde_json::from_value(credential)?)
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn can_build_offer_credential_payload() -> Result<()> {
+        let payload = OfferCredentialPayloadBuilder::new(get_draft_credential()?)
+            .credential_status_included(true)
+            .required_reveal_statements(vec![1])
+            .build()?;
+
+        assert_eq!(payload.draft_credential.issuer, ISSUER_DID);
+        assert_eq!(payload.required_reveal_statements, vec![1]);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_offer_credential_payload_without_claims() -> Result<()> {
+        let mut draft_credential = get_draft_credential()?;
+        draft_credential.credential_subject.data.clear();
+
+        match OfferCredentialPayloadBuilder::new(draft_credential).build() {
+            Ok(_) => assert!(false, "draft credential without claims should not be accepted"),
+            Err(CredentialError::InvalidCredentialOffer(_)) => {}
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "did-sidetree")]
+    fn rejects_offer_credential_payload_with_invalid_subject() -> Result<()> {
+        let mut draft_credential = get_draft_credential()?;
+        draft_credential.credential_subject.id = Some("not a did".to_string());
+
+        match OfferCredentialPayloadBuilder::new(draft_credential).build() {
+            Ok(_) => assert!(false, "invalid subject should not be accepted"),
+            Err(CredentialError::NotADid(value, name)) => {
+                assert_eq!(value, "not a did");
+                assert_eq!(name, "subject");
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
 }
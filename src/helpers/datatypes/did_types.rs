@@ -14,7 +14,12 @@
   limitations under the License.
 */
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 use std::str::FromStr;
+use thiserror::Error;
+
+use crate::api::VadeEvanError;
 
 #[cfg(all(feature = "did-sidetree", feature = "target-c-sdk"))]
 pub const TYPE_SIDETREE_OPTIONS: &str = r#"{ "type": "sidetree", "waitForCompletion":false }"#;
@@ -23,7 +28,7 @@ pub const TYPE_SIDETREE_OPTIONS: &str = r#"{ "type": "sidetree", "waitForComplet
 #[allow(dead_code)]
 pub const EVAN_METHOD: &str = "did:evan";
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum DIDOperationType {
     AddKey,
     RemoveKey,
@@ -46,27 +51,188 @@ impl FromStr for DIDOperationType {
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct DidDocumentResult<T> {
     pub did_document: T,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct IdentityDidDocument {
     pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub controller: Option<String>,
     pub verification_method: Option<Vec<VerificationMethod>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<Vec<VerificationRelationship>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assertion_method: Option<Vec<VerificationRelationship>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capability_invocation: Option<Vec<VerificationRelationship>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capability_delegation: Option<Vec<VerificationRelationship>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_agreement: Option<Vec<VerificationRelationship>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<Vec<DidService>>,
+}
+
+impl IdentityDidDocument {
+    /// Gets verification relationship entries for a proof purpose like "assertionMethod",
+    /// returns `None` for unknown purposes.
+    pub fn get_verification_relationship(
+        &self,
+        proof_purpose: &str,
+    ) -> Option<&Vec<VerificationRelationship>> {
+        match proof_purpose {
+            "authentication" => self.authentication.as_ref(),
+            "assertionMethod" => self.assertion_method.as_ref(),
+            "capabilityInvocation" => self.capability_invocation.as_ref(),
+            "capabilityDelegation" => self.capability_delegation.as_ref(),
+            "keyAgreement" => self.key_agreement.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Serializes this document to JSON with object keys sorted, so documents with the same
+    /// content can be compared regardless of key order or whitespace of their source.
+    pub fn canonicalize(&self) -> Result<String, VadeEvanError> {
+        let value = serde_json::to_value(self).map_err(|err| VadeEvanError::InternalError {
+            source_message: format!("could not serialize DID document; {}", err),
+        })?;
+
+        Ok(sort_keys(value).to_string())
+    }
+
+    /// Checks that this document conforms to the DID Core data model before it is written: the
+    /// `id` has to be a DID, verification relationships may only reference verification methods
+    /// of this document and no verification method may have an empty key.
+    ///
+    /// # Returns
+    /// * `Vec<ValidationError>` - all problems found, if the document is invalid
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = vec![];
+        if !self.id.starts_with("did:") || self.id.len() <= "did:".len() {
+            errors.push(ValidationError::InvalidId(self.id.to_owned()));
+        }
+
+        let verification_methods = self.verification_method.as_deref().unwrap_or_default();
+        let mut embedded_methods = vec![];
+        for proof_purpose in VERIFICATION_RELATIONSHIPS {
+            for relationship in self
+                .get_verification_relationship(proof_purpose)
+                .map(|relationships| relationships.as_slice())
+                .unwrap_or_default()
+            {
+                match relationship {
+                    VerificationRelationship::Reference(reference) => {
+                        if !verification_methods
+                            .iter()
+                            .any(|method| self.is_same_method_id(&method.id, reference))
+                        {
+                            errors.push(ValidationError::DanglingReference {
+                                relationship: proof_purpose.to_string(),
+                                id: reference.to_owned(),
+                            });
+                        }
+                    }
+                    VerificationRelationship::Embedded(method) => embedded_methods.push(method),
+                }
+            }
+        }
+
+        for method in verification_methods.iter().chain(embedded_methods) {
+            let key = &method.public_key_jwk;
+            if key.x.is_empty() || key.y.as_deref() == Some("") {
+                errors.push(ValidationError::EmptyKey(method.id.to_owned()));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Compares verification method ids, that may be given relative to this document ("#key-1")
+    /// or with the document's DID as prefix.
+    fn is_same_method_id(&self, a: &str, b: &str) -> bool {
+        let strip_did = |id: &str| id.strip_prefix(self.id.as_str()).unwrap_or(id).to_owned();
+        strip_did(a) == strip_did(b)
+    }
+}
+
+pub(crate) const VERIFICATION_RELATIONSHIPS: [&str; 5] = [
+    "authentication",
+    "assertionMethod",
+    "capabilityInvocation",
+    "capabilityDelegation",
+    "keyAgreement",
+];
+
+// sorts object keys recursively, array order is kept as it may be significant
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(properties) => {
+            let mut properties: Vec<(String, Value)> = properties.into_iter().collect();
+            properties.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+            Value::Object(
+                properties
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+/// Problem found when validating or building an [`IdentityDidDocument`].
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum ValidationError {
+    #[error(r#"DID document id "{0}" is not a DID"#)]
+    InvalidId(String),
+    #[error(r#"{relationship} references unknown verification method "{id}""#)]
+    DanglingReference { relationship: String, id: String },
+    #[error(r#"verification method "{0}" has an empty key"#)]
+    EmptyKey(String),
+    #[error("DID document requires at least one verification method")]
+    MissingVerificationMethod,
+}
+
+/// Entry of a verification relationship, either a reference to a verification method or an
+/// embedded verification method.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum VerificationRelationship {
+    Reference(String),
+    Embedded(VerificationMethod),
+}
+
+impl VerificationRelationship {
+    pub fn id(&self) -> &str {
+        match self {
+            VerificationRelationship::Reference(id) => id,
+            VerificationRelationship::Embedded(method) => &method.id,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct VerificationMethod {
     pub id: String,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub controller: Option<String>,
     pub public_key_jwk: PublicKeyJwk,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicKeyJwk {
     pub crv: String,
@@ -74,3 +240,435 @@ pub struct PublicKeyJwk {
     pub x: String,
     pub y: Option<String>,
 }
+
+// number of bytes of the key hash used in generated verification method ids
+const METHOD_ID_HASH_LENGTH: usize = 8;
+
+impl PublicKeyJwk {
+    /// Derives a stable verification method id from key type and key hash, e.g.
+    /// "#bbs-1a2b3c4d5e6f7a8b".
+    pub fn get_method_id(&self) -> String {
+        let key_type = match self.crv.as_str() {
+            "BLS12381_G2" => "bbs",
+            "secp256k1" => "secp256k1",
+            "Ed25519" => "ed25519",
+            _ => "key",
+        };
+        let mut hasher = Sha256::new();
+        let y = self.y.as_deref().unwrap_or_default();
+        for value in [self.kty.as_str(), self.crv.as_str(), self.x.as_str(), y] {
+            hasher.update(value.as_bytes());
+            // separate values, so different splits of the same bytes result in different hashes
+            hasher.update([0]);
+        }
+
+        let hash = hasher.finalize();
+
+        format!("#{}-{}", key_type, hex::encode(&hash[..METHOD_ID_HASH_LENGTH]))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+}
+
+/// Builds an [`IdentityDidDocument`], e.g. to be used as initial document for a long-form DID.
+///
+/// # Example
+///
+/// ```
+/// use vade_evan::{DidDocumentBuilder, PublicKeyJwk, VerificationMethod};
+///
+/// let did_document = DidDocumentBuilder::new("did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA")
+///     .add_verification_method(VerificationMethod {
+///         id: "#bbs-key-1".to_string(),
+///         type_: Some("Bls12381G2Key2020".to_string()),
+///         controller: None,
+///         public_key_jwk: PublicKeyJwk {
+///             crv: "BLS12381_G2".to_string(),
+///             kty: "EC".to_string(),
+///             x: "qWZ7EGhzYsSlBq4mLhNal6cHXBD88ZfncdbEWQoue6SaAbZ7k56IxsjcvuXD6LGYDgMgtjTHnBraaMRiwJVBJenXgOT8nto7ZUTO/TvCXwtyPMzGrLM5JNJdEaPP4QJN".to_string(),
+///             y: None,
+///         },
+///     })
+///     .add_assertion_method("#bbs-key-1")
+///     .build();
+/// assert!(did_document.is_ok());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DidDocumentBuilder {
+    id: String,
+    controller: Option<String>,
+    verification_methods: Vec<VerificationMethod>,
+    authentication: Vec<VerificationRelationship>,
+    assertion_method: Vec<VerificationRelationship>,
+    services: Vec<DidService>,
+}
+
+impl DidDocumentBuilder {
+    pub fn new(id: &str) -> Self {
+        DidDocumentBuilder {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn controller(mut self, controller: &str) -> Self {
+        self.controller = Some(controller.to_string());
+        self
+    }
+
+    /// Adds a verification method. If its id is empty, an id is derived from its key with
+    /// [`PublicKeyJwk::get_method_id`], with a numeric suffix if that id is already in use.
+    pub fn add_verification_method(mut self, mut verification_method: VerificationMethod) -> Self {
+        if verification_method.id.is_empty() {
+            let method_id = verification_method.public_key_jwk.get_method_id();
+            let mut unique_method_id = method_id.to_owned();
+            let mut suffix = 1;
+            while self
+                .verification_methods
+                .iter()
+                .any(|method| method.id == unique_method_id)
+            {
+                suffix += 1;
+                unique_method_id = format!("{}-{}", method_id, suffix);
+            }
+            verification_method.id = unique_method_id;
+        }
+        self.verification_methods.push(verification_method);
+        self
+    }
+
+    /// Authorizes a verification method of this document, referenced by its id, for authentication.
+    pub fn add_authentication(mut self, verification_method_id: &str) -> Self {
+        self.authentication.push(VerificationRelationship::Reference(
+            verification_method_id.to_string(),
+        ));
+        self
+    }
+
+    /// Authorizes a verification method of this document, referenced by its id, for assertions.
+    pub fn add_assertion_method(mut self, verification_method_id: &str) -> Self {
+        self.assertion_method.push(VerificationRelationship::Reference(
+            verification_method_id.to_string(),
+        ));
+        self
+    }
+
+    pub fn add_service(mut self, service: DidService) -> Self {
+        self.services.push(service);
+        self
+    }
+
+    /// Builds the DID document and validates it with [`IdentityDidDocument::validate`].
+    ///
+    /// # Returns
+    /// * `IdentityDidDocument` - document with `id`, at least one verification method and
+    ///   verification relationships, that only reference methods of this document
+    pub fn build(self) -> Result<IdentityDidDocument, VadeEvanError> {
+        let has_verification_methods = !self.verification_methods.is_empty();
+        let to_option = |relationships: Vec<VerificationRelationship>| {
+            if relationships.is_empty() {
+                None
+            } else {
+                Some(relationships)
+            }
+        };
+
+        let did_document = IdentityDidDocument {
+            id: self.id,
+            controller: self.controller,
+            verification_method: Some(self.verification_methods),
+            authentication: to_option(self.authentication),
+            assertion_method: to_option(self.assertion_method),
+            capability_invocation: None,
+            capability_delegation: None,
+            key_agreement: None,
+            service: if self.services.is_empty() {
+                None
+            } else {
+                Some(self.services)
+            },
+        };
+
+        let mut errors = match did_document.validate() {
+            Ok(()) => vec![],
+            Err(errors) => errors,
+        };
+        if !has_verification_methods {
+            errors.push(ValidationError::MissingVerificationMethod);
+        }
+        if !errors.is_empty() {
+            return Err(VadeEvanError::InvalidDidDocument { errors });
+        }
+
+        Ok(did_document)
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))]
+mod tests {
+    use anyhow::Result;
+    use serde_json::json;
+
+    use crate::api::VadeEvanError;
+
+    use super::{
+        DidDocumentBuilder,
+        DidService,
+        IdentityDidDocument,
+        PublicKeyJwk,
+        ValidationError,
+        VerificationMethod,
+        VerificationRelationship,
+    };
+
+    const DID: &str = "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA";
+
+    fn get_verification_method(id: &str, x: &str) -> VerificationMethod {
+        VerificationMethod {
+            id: id.to_string(),
+            type_: Some("JsonWebKey2020".to_string()),
+            controller: Some(DID.to_string()),
+            public_key_jwk: PublicKeyJwk {
+                crv: "secp256k1".to_string(),
+                kty: "EC".to_string(),
+                x: x.to_string(),
+                y: Some("y".to_string()),
+            },
+        }
+    }
+
+    #[test]
+    fn can_build_did_document_with_keys_and_service() -> Result<()> {
+        let did_document = DidDocumentBuilder::new(DID)
+            .controller(DID)
+            .add_verification_method(get_verification_method("#key-1", "x1"))
+            .add_verification_method(get_verification_method("#key-2", "x2"))
+            .add_authentication("#key-1")
+            .add_assertion_method("#key-2")
+            .add_service(DidService {
+                id: "#service-1".to_string(),
+                type_: "CustomService".to_string(),
+                service_endpoint: "https://www.example.service".to_string(),
+            })
+            .build()?;
+
+        assert_eq!(
+            serde_json::to_value(&did_document)?,
+            json!({
+                "id": DID,
+                "controller": DID,
+                "verificationMethod": [
+                    {
+                        "id": "#key-1",
+                        "type": "JsonWebKey2020",
+                        "controller": DID,
+                        "publicKeyJwk": { "crv": "secp256k1", "kty": "EC", "x": "x1", "y": "y" }
+                    },
+                    {
+                        "id": "#key-2",
+                        "type": "JsonWebKey2020",
+                        "controller": DID,
+                        "publicKeyJwk": { "crv": "secp256k1", "kty": "EC", "x": "x2", "y": "y" }
+                    }
+                ],
+                "authentication": ["#key-1"],
+                "assertionMethod": ["#key-2"],
+                "service": [
+                    {
+                        "id": "#service-1",
+                        "type": "CustomService",
+                        "serviceEndpoint": "https://www.example.service"
+                    }
+                ]
+            }),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn derives_stable_and_distinct_method_ids_from_keys() {
+        let key_1 = get_verification_method("", "x1").public_key_jwk;
+        let key_2 = get_verification_method("", "x2").public_key_jwk;
+
+        assert_eq!(key_1.get_method_id(), key_1.clone().get_method_id());
+        assert_ne!(key_1.get_method_id(), key_2.get_method_id());
+        assert!(key_1.get_method_id().starts_with("#secp256k1-"));
+        assert_eq!(key_1.get_method_id().len(), "#secp256k1-".len() + 16);
+    }
+
+    #[test]
+    fn builder_assigns_unique_method_ids_to_methods_without_id() -> Result<()> {
+        let method_id = get_verification_method("", "x1").public_key_jwk.get_method_id();
+
+        let did_document = DidDocumentBuilder::new(DID)
+            .add_verification_method(get_verification_method("", "x1"))
+            .add_verification_method(get_verification_method("", "x1"))
+            .add_verification_method(get_verification_method("", "x2"))
+            .add_assertion_method(&method_id)
+            .build()?;
+
+        let method_ids: Vec<String> = did_document
+            .verification_method
+            .unwrap_or_default()
+            .into_iter()
+            .map(|method| method.id)
+            .collect();
+        assert_eq!(method_ids[0], method_id);
+        assert_eq!(method_ids[1], format!("{}-2", method_id));
+        assert_ne!(method_ids[2], method_id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_did_document_without_verification_method() {
+        let result = DidDocumentBuilder::new(DID).build();
+
+        match result {
+            Ok(_) => assert!(false, "got unexpected result instead of error"),
+            Err(VadeEvanError::InvalidDidDocument { errors }) => {
+                assert_eq!(errors, vec![ValidationError::MissingVerificationMethod])
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+    }
+
+    #[test]
+    fn rejects_relationship_to_unknown_verification_method() {
+        let result = DidDocumentBuilder::new(DID)
+            .add_verification_method(get_verification_method("#key-1", "x1"))
+            .add_authentication("#key-2")
+            .build();
+
+        match result {
+            Ok(_) => assert!(false, "got unexpected result instead of error"),
+            Err(VadeEvanError::InvalidDidDocument { errors }) => assert_eq!(
+                errors,
+                vec![ValidationError::DanglingReference {
+                    relationship: "authentication".to_string(),
+                    id: "#key-2".to_string(),
+                }],
+            ),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+    }
+
+    #[test]
+    fn builder_accepts_relationship_to_method_id_with_did() -> Result<()> {
+        let did_document = DidDocumentBuilder::new(DID)
+            .add_verification_method(get_verification_method("#key-1", "x1"))
+            .add_authentication(&format!("{}#key-1", DID))
+            .build()?;
+
+        assert_eq!(did_document.validate(), Ok(()));
+
+        Ok(())
+    }
+
+    fn get_did_document() -> Result<IdentityDidDocument> {
+        Ok(DidDocumentBuilder::new(DID)
+            .add_verification_method(get_verification_method("#key-1", "x1"))
+            .add_verification_method(get_verification_method("#key-2", "x2"))
+            .add_authentication("#key-1")
+            .add_assertion_method("#key-2")
+            .build()?)
+    }
+
+    #[test]
+    fn can_validate_valid_did_document() -> Result<()> {
+        let mut did_document = get_did_document()?;
+        // references may also include the document's DID
+        did_document.capability_invocation = Some(vec![VerificationRelationship::Reference(
+            format!("{}#key-2", DID),
+        )]);
+
+        assert_eq!(did_document.validate(), Ok(()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation_rejects_dangling_reference() -> Result<()> {
+        let mut did_document = get_did_document()?;
+        did_document.key_agreement = Some(vec![VerificationRelationship::Reference(
+            "#key-3".to_string(),
+        )]);
+
+        assert_eq!(
+            did_document.validate(),
+            Err(vec![ValidationError::DanglingReference {
+                relationship: "keyAgreement".to_string(),
+                id: "#key-3".to_string(),
+            }]),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation_rejects_missing_id() -> Result<()> {
+        let mut did_document = get_did_document()?;
+        did_document.id = String::new();
+
+        assert_eq!(
+            did_document.validate(),
+            Err(vec![ValidationError::InvalidId(String::new())]),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation_rejects_empty_key() -> Result<()> {
+        let mut did_document = get_did_document()?;
+        did_document.verification_method = Some(vec![
+            get_verification_method("#key-1", ""),
+            get_verification_method("#key-2", "x2"),
+        ]);
+
+        assert_eq!(
+            did_document.validate(),
+            Err(vec![ValidationError::EmptyKey("#key-1".to_string())]),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonicalizes_documents_independent_of_key_order() -> Result<()> {
+        let document_a: IdentityDidDocument = serde_json::from_str(&format!(
+            r##"{{
+                "id": "{0}",
+                "verificationMethod": [{{
+                    "id": "#key-1",
+                    "type": "JsonWebKey2020",
+                    "publicKeyJwk": {{ "kty": "EC", "crv": "secp256k1", "x": "x1", "y": "y" }}
+                }}],
+                "assertionMethod": ["#key-1"]
+            }}"##,
+            DID
+        ))?;
+        let document_b: IdentityDidDocument = serde_json::from_str(&format!(
+            r##"{{"assertionMethod":["#key-1"],"verificationMethod":[{{"publicKeyJwk":{{"y":"y",
+                "x":"x1","crv":"secp256k1","kty":"EC"}},"type":"JsonWebKey2020","id":"#key-1"}}],
+                "id":"{0}"}}"##,
+            DID
+        ))?;
+
+        let canonical = document_a.canonicalize()?;
+        assert_eq!(canonical, document_b.canonicalize()?);
+        assert_eq!(canonical, document_a.canonicalize()?);
+        assert!(canonical.starts_with(r##"{"assertionMethod":["#key-1"],"id":"##));
+
+        Ok(())
+    }
+}
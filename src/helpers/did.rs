@@ -2,7 +2,9 @@ use std::str::FromStr;
 
 use crate::api::{VadeEvan, VadeEvanError};
 use crate::helpers::datatypes::{DIDOperationType, EVAN_METHOD, TYPE_SIDETREE_OPTIONS};
+use alloy_primitives::keccak256;
 use base64::{decode_config, encode_config, URL_SAFE_NO_PAD};
+use serde_json::{json, Map, Value};
 
 use vade_sidetree::{
     datatypes::{
@@ -26,6 +28,111 @@ use vade_sidetree::{
 
 pub const TYPE_BBS_KEY: &str = "Bls12381G2Key2020";
 pub const TYPE_JSONWEB_KEY: &str = "JsonWebKey2020";
+// multihash prefix and length of sha256 based sidetree DID suffixes
+const SIDETREE_SUFFIX_PREFIX: &str = "Ei";
+const SIDETREE_SUFFIX_LENGTH: usize = 46;
+const SUBSTRATE_NETWORKS: [&str; 2] = ["core", "testcore"];
+
+/// Checks if given DID is a long-form `did:evan` DID, that embeds its initial DID document
+/// as `did:evan:<suffix>:<encoded document>`.
+///
+/// Sidetree long-form DIDs (`did:evan:<suffix>:<encoded initial state>`) share this shape, but
+/// embed the `suffixData` and `delta` of their create operation instead of a DID document. They
+/// are not considered long-form DIDs here, as they are resolved by the sidetree plugin.
+pub fn is_long_form_did(did: &str) -> bool {
+    split_long_form_did(did).is_some()
+}
+
+fn split_long_form_did(did: &str) -> Option<(&str, &str)> {
+    match decode_long_form_did(did)? {
+        (suffix, encoded_document, Value::Object(document))
+            if !is_sidetree_initial_state(&document) =>
+        {
+            Some((suffix, encoded_document))
+        }
+        _ => None,
+    }
+}
+
+fn is_sidetree_long_form_did(did: &str) -> bool {
+    match decode_long_form_did(did) {
+        Some((suffix, _, Value::Object(initial_state))) => {
+            is_sidetree_suffix(suffix) && is_sidetree_initial_state(&initial_state)
+        }
+        _ => false,
+    }
+}
+
+fn decode_long_form_did(did: &str) -> Option<(&str, &str, Value)> {
+    let method_specific_id = did.strip_prefix(&format!("{}:", EVAN_METHOD))?;
+    let mut parts = method_specific_id.split(':');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(suffix), Some(encoded), None) => {
+            let decoded = decode_config(encoded, URL_SAFE_NO_PAD).ok()?;
+            Some((suffix, encoded, serde_json::from_slice(&decoded).ok()?))
+        }
+        _ => None,
+    }
+}
+
+fn is_sidetree_initial_state(value: &Map<String, Value>) -> bool {
+    value.get("suffixData").map_or(false, Value::is_object)
+        && value.get("delta").map_or(false, Value::is_object)
+}
+
+fn get_long_form_suffix(encoded_document: &str) -> String {
+    encode_config(keccak256(encoded_document.as_bytes()), URL_SAFE_NO_PAD)
+}
+
+/// Checks the form of a `did:evan` DID and normalizes it to the form resolvers expect.
+///
+/// Supported are hash-based sidetree DIDs (`did:evan:Ei...`) in short and long form, long-form
+/// DIDs with an embedded DID document and address-based substrate DIDs
+/// (`did:evan:testcore:0x...`), which are normalized to lower case hex. Both identifier forms are independent of each other, so they cannot be converted into
+/// one another.
+///
+/// # Arguments
+/// * `did` - DID to normalize
+///
+/// # Returns
+/// * `String` - normalized DID
+pub fn normalize_evan_did(did: &str) -> Result<String, VadeEvanError> {
+    let invalid_did = |reason: &str| VadeEvanError::InvalidDid {
+        did: did.to_string(),
+        source_message: reason.to_string(),
+    };
+    let method_specific_id = did
+        .strip_prefix(&format!("{}:", EVAN_METHOD))
+        .ok_or_else(|| invalid_did("not a did:evan DID"))?;
+    if is_long_form_did(did) || is_sidetree_long_form_did(did) {
+        return Ok(did.to_string());
+    }
+
+    match method_specific_id.split(':').collect::<Vec<&str>>().as_slice() {
+        [suffix] if is_sidetree_suffix(suffix) => Ok(did.to_string()),
+        [network, address] if SUBSTRATE_NETWORKS.contains(network) && is_address(address) => {
+            Ok(format!("{}:{}:{}", EVAN_METHOD, network, address.to_lowercase()))
+        }
+        _ => Err(invalid_did("unrecognized did:evan identifier")),
+    }
+}
+
+fn is_sidetree_suffix(suffix: &str) -> bool {
+    suffix.starts_with(SIDETREE_SUFFIX_PREFIX)
+        && suffix.len() == SIDETREE_SUFFIX_LENGTH
+        && suffix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn is_address(address: &str) -> bool {
+    match address.strip_prefix("0x") {
+        Some(hex) => {
+            (hex.len() == 40 || hex.len() == 64) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+        None => false,
+    }
+}
 
 pub struct Did<'a> {
     vade_evan: &'a mut VadeEvan,
@@ -295,17 +402,192 @@ impl<'a> Did<'a> {
 
         Ok(result)
     }
+
+    /// Creates a long-form DID, that embeds the given initial DID document and can be resolved
+    /// without network access. The DID suffix is derived from the encoded document, so the
+    /// embedded document cannot be altered without invalidating the DID.
+    pub fn create_long_form(self, initial_document: &str) -> Result<String, VadeEvanError> {
+        let document: Value = serde_json::from_str(initial_document).map_err(|err| {
+            VadeEvanError::InternalError {
+                source_message: err.to_string(),
+            }
+        })?;
+        if !document.is_object() {
+            return Err(VadeEvanError::InternalError {
+                source_message: "initial DID document has to be a JSON object".to_owned(),
+            });
+        }
+        let document_bytes =
+            serde_json::to_vec(&document).map_err(|err| VadeEvanError::InternalError {
+                source_message: err.to_string(),
+            })?;
+        let encoded_document = encode_config(document_bytes, URL_SAFE_NO_PAD);
+
+        Ok(format!(
+            "{}:{}:{}",
+            EVAN_METHOD,
+            get_long_form_suffix(&encoded_document),
+            encoded_document,
+        ))
+    }
+
+    /// Resolves a long-form DID locally by decoding its embedded DID document.
+    pub fn resolve_long_form(self, did: &str) -> Result<String, VadeEvanError> {
+        let (suffix, encoded_document) =
+            split_long_form_did(did).ok_or_else(|| VadeEvanError::InternalError {
+                source_message: format!("{} is not a long-form DID", did),
+            })?;
+        if suffix != get_long_form_suffix(encoded_document) {
+            return Err(VadeEvanError::InternalError {
+                source_message: format!("suffix of long-form DID {} does not match document", did),
+            });
+        }
+        let document_bytes = decode_config(encoded_document, URL_SAFE_NO_PAD).map_err(|err| {
+            VadeEvanError::InternalError {
+                source_message: err.to_string(),
+            }
+        })?;
+        let mut document: Value =
+            serde_json::from_slice(&document_bytes).map_err(|err| VadeEvanError::InternalError {
+                source_message: err.to_string(),
+            })?;
+        document["id"] = Value::String(did.to_owned());
+
+        serde_json::to_string(&json!({ "didDocument": document })).map_err(|err| {
+            VadeEvanError::InternalError {
+                source_message: err.to_string(),
+            }
+        })
+    }
 }
 
 #[cfg(test)]
 #[cfg(feature = "did-sidetree")]
 #[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))]
 mod tests {
+    use super::{is_long_form_did, normalize_evan_did};
     use crate::{VadeEvan, VadeEvanError, DEFAULT_SIGNER, DEFAULT_TARGET};
     use anyhow::Result;
+    use base64::{encode_config, URL_SAFE_NO_PAD};
+    use serde_json::json;
     use serial_test::serial;
     use vade_sidetree::datatypes::{DidCreateResponse, JsonWebKey, Service, SidetreeDidDocument};
 
+    const INITIAL_DOCUMENT: &str = r#"{
+        "verificationMethod": [
+            {
+                "id": "#bbs-key-1",
+                "controller": "",
+                "type": "Bls12381G2Key2020",
+                "publicKeyJwk": {
+                    "kty": "EC",
+                    "crv": "BLS12381_G2",
+                    "x": "qWZ7EGhzYsSlBq4mLhNal6cHXBD88ZfncdbEWQoue6SaAbZ7k56IxsjcvuVOybIhDxOoFe5rjn2wDVKLBtJsFOY8Cn8UwYvgF1w9KrVDeoMkDBsvOANVGT73cGcpdHJN"
+                }
+            }
+        ],
+        "assertionMethod": ["#bbs-key-1"]
+    }"#;
+
+    /// Gets a sidetree long-form DID, that embeds the initial state of its create operation.
+    fn get_sidetree_long_form_did() -> String {
+        let initial_state = json!({
+            "suffixData": {
+                "deltaHash": "EiCfDWRnYlcD9EGA3d_5Z1AHu-iYqMbJ9nfiqdz5S8VDbg",
+                "recoveryCommitment": "EiBfOZdMtU6OBw8Pk879QtZ-2J-9FbbjSZyoaA_bqD4zhA"
+            },
+            "delta": {
+                "patches": [{ "action": "replace", "document": { "publicKeys": [] } }],
+                "updateCommitment": "EiDKIkwqO69IPG3pOlHkdb86nYt0aNxSHZu2r-bhEznjdA"
+            }
+        });
+
+        format!(
+            "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA:{}",
+            encode_config(initial_state.to_string(), URL_SAFE_NO_PAD),
+        )
+    }
+
+    #[tokio::test]
+    async fn helper_did_can_create_and_resolve_long_form_did() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let did = vade_evan.helper_did_create_long_form(INITIAL_DOCUMENT)?;
+        assert!(did.starts_with("did:evan:"));
+
+        let resolved = vade_evan.did_resolve(&did).await?;
+        let resolved: serde_json::Value = serde_json::from_str(&resolved)?;
+        let mut expected: serde_json::Value = serde_json::from_str(INITIAL_DOCUMENT)?;
+        expected["id"] = serde_json::Value::String(did.clone());
+        assert_eq!(resolved["didDocument"], expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalizes_evan_dids() -> Result<()> {
+        let sidetree_did = "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA";
+        assert_eq!(normalize_evan_did(sidetree_did)?, sidetree_did);
+
+        assert_eq!(
+            normalize_evan_did("did:evan:testcore:0x0D87204C3957D73B68AE28D0AF961D3C72403906")?,
+            "did:evan:testcore:0x0d87204c3957d73b68ae28d0af961d3c72403906",
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn distinguishes_sidetree_long_form_dids() -> Result<()> {
+        let sidetree_did = get_sidetree_long_form_did();
+        assert!(!is_long_form_did(&sidetree_did));
+        assert_eq!(normalize_evan_did(&sidetree_did)?, sidetree_did);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_unrecognized_evan_dids() {
+        for did in [
+            "did:example:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9",
+            "did:evan:testcore:0x0d87204c",
+            "did:evan:othernet:0x0d87204c3957d73b68ae28d0af961d3c72403906",
+        ] {
+            match normalize_evan_did(did) {
+                Ok(_) => assert!(false, "got no error for invalid DID {}", did),
+                Err(VadeEvanError::InvalidDid { did: value, .. }) => assert_eq!(value, did),
+                Err(err) => assert!(false, "got unexpected error: {}", err),
+            };
+        }
+    }
+
+    #[tokio::test]
+    async fn helper_did_rejects_long_form_did_with_altered_document() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let did = vade_evan.helper_did_create_long_form(INITIAL_DOCUMENT)?;
+        let other_did = vade_evan.helper_did_create_long_form(r#"{ "service": [] }"#)?;
+        let suffix = did.split(':').nth(2).unwrap_or_default();
+        let encoded_document = other_did.split(':').nth(3).unwrap_or_default();
+        let tampered_did = format!("did:evan:{}:{}", suffix, encoded_document);
+
+        let result = vade_evan.did_resolve(&tampered_did).await;
+
+        match result {
+            Ok(_) => assert!(false, "got unexpected result instead of error"),
+            Err(err) => assert!(err.to_string().contains("does not match document")),
+        };
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[serial]
     async fn helper_did_can_create_did_with_update_and_recovery_keys() -> Result<()> {
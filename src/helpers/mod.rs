@@ -11,8 +11,26 @@ mod version_info;
 
 #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
 pub(crate) use credential::{Credential, CredentialError};
+#[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+pub use credential::{AttributeMapping, CredentialOfferOptions, RevocationState};
+#[cfg(feature = "did-sidetree")]
+pub(crate) use did::{is_long_form_did, Did};
 #[cfg(feature = "did-sidetree")]
-pub(crate) use did::Did;
+pub use did::normalize_evan_did;
 #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
 pub(crate) use presentation::{Presentation, PresentationError};
+#[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+pub use presentation::ProofRequestBuilder;
 pub(crate) use version_info::VersionInfo;
+
+pub(crate) use datatypes::DidDocumentResult;
+
+pub use datatypes::{
+    DidDocumentBuilder,
+    DidService,
+    IdentityDidDocument,
+    PublicKeyJwk,
+    ValidationError,
+    VerificationMethod,
+    VerificationRelationship,
+};
@@ -27,7 +27,7 @@ use super::{
         SharedError,
     },
 };
-use crate::api::VadeEvan;
+use crate::api::{VadeEvan, VadeEvanError};
 use crate::helpers::credential::Credential;
 use crate::helpers::datatypes::EVAN_METHOD;
 
@@ -53,6 +53,12 @@ pub enum PresentationError {
     SchemaNotFound(String),
     #[error(r#"value "{0}" given for "{1} is not a DID""#)]
     NotADid(String, String),
+    #[error("requested attributes have not been revealed: {0}")]
+    RequestedAttributesNotRevealed(String),
+    #[error("presentation could not be verified; {0}")]
+    PresentationNotVerified(String),
+    #[error("statements required to be revealed by issuer are hidden: {0}")]
+    RequiredStatementHidden(String),
 }
 
 impl PresentationError {
@@ -100,6 +106,148 @@ pub fn fail_if_not_a_did(to_check: &str, name: &str) -> Result<(), PresentationE
     Ok(())
 }
 
+/// Checks that all statements an issuer requires to be revealed are present in the revealed
+/// data of a presented credential. Required indices, that do not belong to a `credentialSubject`
+/// attribute, refer to statements that are always revealed.
+///
+/// # Arguments
+///
+/// * `required_reveal_statements` - indices of statements that have to be revealed
+/// * `name_to_index_map` - attribute names of the credential's schema mapped to their indices
+/// * `revealed_data` - revealed `credentialSubject.data` of the presented credential
+///
+/// # Returns
+/// `()` or `PresentationError::RequiredStatementHidden`
+fn fail_if_required_statements_hidden(
+    required_reveal_statements: &[usize],
+    name_to_index_map: &HashMap<String, usize>,
+    revealed_data: &HashMap<String, String>,
+) -> Result<(), PresentationError> {
+    let mut hidden: Vec<String> = name_to_index_map
+        .iter()
+        .filter(|(name, index)| {
+            required_reveal_statements.contains(index) && !revealed_data.contains_key(*name)
+        })
+        .map(|(name, index)| format!(r#""{}" ({})"#, name, index))
+        .collect();
+
+    if !hidden.is_empty() {
+        hidden.sort();
+        return Err(PresentationError::RequiredStatementHidden(hidden.join(", ")));
+    }
+
+    Ok(())
+}
+
+/// Builds the payload `vc_zkp_request_proof` expects for requesting a proof from scratch.
+/// Revealed attributes are validated against the requested schemas when building the payload.
+#[derive(Default)]
+pub struct ProofRequestBuilder {
+    verifier_did: Option<String>,
+    schemas: Vec<RequestedSchema>,
+    // attributes given before any schema has been added
+    unassigned_attributes: Vec<String>,
+}
+
+struct RequestedSchema {
+    schema_did: String,
+    // attributes to reveal, all attributes are revealed if none are given
+    attributes: Vec<String>,
+    // credentials with `credentialStatus` have additional messages, that shift attribute indices
+    is_revocable: bool,
+}
+
+impl ProofRequestBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn verifier(mut self, verifier_did: &str) -> Self {
+        self.verifier_did = Some(verifier_did.to_string());
+        self
+    }
+
+    /// Adds a schema to request a proof for, following calls to [`reveal`](Self::reveal) and
+    /// [`revocable`](Self::revocable) refer to this schema.
+    pub fn for_schema(mut self, schema_did: &str) -> Self {
+        self.schemas.push(RequestedSchema {
+            schema_did: schema_did.to_string(),
+            attributes: vec![],
+            is_revocable: true,
+        });
+        self
+    }
+
+    /// Requests to reveal an attribute of the schema last added with
+    /// [`for_schema`](Self::for_schema).
+    pub fn reveal(mut self, attribute: &str) -> Self {
+        match self.schemas.last_mut() {
+            Some(schema) => schema.attributes.push(attribute.to_string()),
+            None => self.unassigned_attributes.push(attribute.to_string()),
+        }
+        self
+    }
+
+    /// Sets whether credentials of the schema last added with [`for_schema`](Self::for_schema)
+    /// are revocable, i.e. have a `credentialStatus`. Defaults to `true`.
+    pub fn revocable(mut self, is_revocable: bool) -> Self {
+        if let Some(schema) = self.schemas.last_mut() {
+            schema.is_revocable = is_revocable;
+        }
+        self
+    }
+
+    /// Resolves the requested schemas, checks that all revealed attributes are part of them and
+    /// returns a `RequestProofPayload` as JSON.
+    pub async fn build(self, vade_evan: &mut VadeEvan) -> Result<String, VadeEvanError> {
+        Ok(self.build_payload(vade_evan).await?)
+    }
+
+    async fn build_payload(self, vade_evan: &mut VadeEvan) -> Result<String, PresentationError> {
+        if !self.unassigned_attributes.is_empty() {
+            return Err(PresentationError::InvalidRevealedAttributes(format!(
+                "attributes {} have been given before a schema",
+                self.unassigned_attributes.join(", ")
+            )));
+        }
+        if self.schemas.is_empty() {
+            return Err(PresentationError::InternalError(
+                "proof request requires at least one schema".to_string(),
+            ));
+        }
+
+        let mut presentation = Presentation::new(vade_evan)?;
+        let mut schemas: Vec<String> = vec![];
+        let mut reveal_attributes: HashMap<String, Vec<usize>> = HashMap::new();
+        for schema in self.schemas {
+            let attributes = if schema.attributes.is_empty() {
+                None
+            } else {
+                Some(schema.attributes)
+            };
+            reveal_attributes.extend(
+                presentation
+                    .get_reveal_attributes_indices_map(
+                        &schema.schema_did,
+                        attributes,
+                        schema.is_revocable,
+                    )
+                    .await?,
+            );
+            schemas.push(schema.schema_did);
+        }
+        let proof_request_payload =
+            RequestProofPayload::FromScratch(RequestProofPayloadFromScratch {
+                verifier_did: self.verifier_did,
+                schemas,
+                reveal_attributes,
+            });
+
+        serde_json::to_string(&proof_request_payload)
+            .map_err(PresentationError::to_serialization_error("RequestProofPayload"))
+    }
+}
+
 pub struct Presentation<'a> {
     vade_evan: &'a mut VadeEvan,
 }
@@ -137,8 +285,9 @@ impl<'a> Presentation<'a> {
         let proof_proposal_payload = ProposeProofPayload {
             verifier_did: None,
             schemas: vec![schema_did.to_string()],
+            // proposals are created without a credential, assume a revocable one
             reveal_attributes: self
-                .get_reveal_attributes_indices_map(schema_did, revealed_attributes_parsed)
+                .get_reveal_attributes_indices_map(schema_did, revealed_attributes_parsed, true)
                 .await?,
         };
 
@@ -200,8 +349,10 @@ impl<'a> Presentation<'a> {
             RequestProofPayload::FromScratch(RequestProofPayloadFromScratch {
                 verifier_did: None,
                 schemas: vec![schema_did.to_string()],
+                // requests are created without a credential, assume a revocable one, use
+                // `ProofRequestBuilder` to request proofs for non-revocable credentials
                 reveal_attributes: self
-                    .get_reveal_attributes_indices_map(schema_did, revealed_attributes_parsed)
+                    .get_reveal_attributes_indices_map(schema_did, revealed_attributes_parsed, true)
                     .await?,
             });
 
@@ -218,6 +369,8 @@ impl<'a> Presentation<'a> {
     /// Verifies a presentation.
     ///
     /// The function checks if the presentation is valid against the provided proof request.
+    /// If a presented credential lists `requiredRevealStatements` in its proof, all of these
+    /// statements have to be revealed.
     ///
     /// # Arguments
     ///
@@ -247,18 +400,11 @@ impl<'a> Presentation<'a> {
 
         let schema_did = &credential.credential_schema.id;
 
-        let mut map_for_nquads: Map<String, Value> = Map::new();
-        map_for_nquads.insert("@context".to_owned(), credential.context.to_owned().into());
-
-        for (k, v) in credential.credential_subject.data.to_owned() {
-            map_for_nquads.insert(k, serde_json::Value::String(v));
-        }
-
         let mut helper_credential = Credential::new(self.vade_evan)
             .map_err(|err| PresentationError::InternalError(err.to_string()))?;
 
         let public_key_issuer = helper_credential
-            .get_issuer_public_key(&credential.issuer, "#bbs-key-1")
+            .get_issuer_public_key_for_purpose(&credential.issuer, "#bbs-key-1", "assertionMethod")
             .await
             .map_err(|err| PresentationError::InternalError(err.to_string()))?;
         let mut keys_to_schema_map = HashMap::new();
@@ -279,6 +425,7 @@ impl<'a> Presentation<'a> {
         let mut presentation_value: Value = serde_json::from_str(presentation_str).map_err(
             PresentationError::to_deserialization_error("presentation", &presentation_str),
         )?;
+        self.check_required_reveal_statements(&presentation, &presentation_value).await?;
 
         let presentation_value_with_proof =
             presentation_value.as_object_mut().ok_or_else(|| {
@@ -318,6 +465,98 @@ impl<'a> Presentation<'a> {
             .map_err(|err| PresentationError::VadeEvanError(err.to_string()))
     }
 
+    /// Verifies a presentation and checks that it reveals all attributes requested by the verifier.
+    ///
+    /// In addition to the checks done by [`verify_presentation`](Self::verify_presentation),
+    /// the attribute indices of every sub proof request are mapped back to attribute names and
+    /// each of them has to be present in the revealed data of the matching credential.
+    ///
+    /// # Arguments
+    ///
+    /// * `presentation_str` - verifiable presentation from the holder as JSON string
+    /// * `proof_request_str` - proof request from the verifier as JSON string
+    ///
+    /// # Returns
+    /// * `Result<String, PresentationError>` - revealed attributes as JSON, mapping schema DIDs
+    ///   to attribute names and their values
+    pub async fn verify_presentation_against_request(
+        &mut self,
+        presentation_str: &str,
+        proof_request_str: &str,
+    ) -> Result<String, PresentationError> {
+        let presentation: ProofPresentation = serde_json::from_str(presentation_str).map_err(
+            PresentationError::to_deserialization_error("presentation", presentation_str),
+        )?;
+        let proof_request: BbsProofRequest = serde_json::from_str(proof_request_str).map_err(
+            PresentationError::to_deserialization_error("proof request", proof_request_str),
+        )?;
+
+        let mut revealed_claims: HashMap<String, HashMap<String, String>> = HashMap::new();
+        let mut not_revealed: Vec<String> = vec![];
+        for sub_proof_request in proof_request.sub_proof_requests.iter() {
+            let schema_did = &sub_proof_request.schema;
+            let credential = presentation
+                .verifiable_credential
+                .iter()
+                .find(|vc| &vc.credential_schema.id == schema_did)
+                .ok_or_else(|| {
+                    PresentationError::InvalidPresentationError(format!(
+                        "no credential found for requested schema {}",
+                        schema_did
+                    ))
+                })?;
+            let (_, name_to_index_map) = self
+                .get_attribute_indices_by_name(schema_did, credential.credential_status.is_some())
+                .await?;
+
+            let mut claims = HashMap::new();
+            for index in sub_proof_request.revealed_attributes.iter() {
+                let attribute_name = name_to_index_map
+                    .iter()
+                    .find(|(_, attribute_index)| *attribute_index == index)
+                    .map(|(name, _)| name.to_owned())
+                    .ok_or_else(|| {
+                        PresentationError::InvalidRevealedAttributes(format!(
+                            "index {} of schema {}",
+                            index, schema_did
+                        ))
+                    })?;
+                match credential.credential_subject.data.get(&attribute_name) {
+                    Some(value) => {
+                        claims.insert(attribute_name, value.to_owned());
+                    }
+                    None => not_revealed.push(format!(r#""{}""#, attribute_name)),
+                }
+            }
+            revealed_claims.insert(schema_did.to_owned(), claims);
+        }
+
+        if !not_revealed.is_empty() {
+            return Err(PresentationError::RequestedAttributesNotRevealed(
+                not_revealed.join(", "),
+            ));
+        }
+
+        let verification_str = self
+            .verify_presentation(presentation_str, proof_request_str)
+            .await?;
+        let verification: Value = serde_json::from_str(&verification_str).map_err(
+            PresentationError::to_deserialization_error("proof verification", &verification_str),
+        )?;
+        if verification["status"] != "verified" {
+            return Err(PresentationError::PresentationNotVerified(
+                verification["reason"]
+                    .as_str()
+                    .unwrap_or("no reason given")
+                    .to_string(),
+            ));
+        }
+
+        serde_json::to_string(&revealed_claims).map_err(PresentationError::to_serialization_error(
+            "revealed claims",
+        ))
+    }
+
     /// Creates a presentation.
     /// The presentation has proof and requested credentials.
     ///
@@ -374,7 +613,11 @@ impl<'a> Presentation<'a> {
                 })
                 .transpose()?;
             let reveal_attributes = self
-                .get_reveal_attributes_indices_map(schema_did, revealed_attributes_parsed)
+                .get_reveal_attributes_indices_map(
+                    schema_did,
+                    revealed_attributes_parsed,
+                    credential.credential_status.is_some(),
+                )
                 .await?;
             for sub_proof in proof_request.sub_proof_requests.iter_mut() {
                 if &sub_proof.schema == schema_did {
@@ -465,35 +708,93 @@ impl<'a> Presentation<'a> {
         Ok(did_result_value.did_document)
     }
 
-    async fn get_reveal_attributes_indices_map(
+    /// Ensures that the credentials of a presentation reveal all statements listed in the
+    /// `requiredRevealStatements` of their proofs.
+    async fn check_required_reveal_statements(
+        &mut self,
+        presentation: &ProofPresentation,
+        presentation_value: &Value,
+    ) -> Result<(), PresentationError> {
+        for (index, credential) in presentation.verifiable_credential.iter().enumerate() {
+            let proof = &presentation_value["verifiableCredential"][index]["proof"];
+            let required_reveal_statements = match proof.get("requiredRevealStatements") {
+                Some(value) => value.to_owned(),
+                None => continue,
+            };
+            let required_reveal_statements: Vec<usize> =
+                serde_json::from_value(required_reveal_statements).map_err(|err| {
+                    PresentationError::InvalidPresentationError(format!(
+                        "could not parse requiredRevealStatements; {}",
+                        err
+                    ))
+                })?;
+            if required_reveal_statements.is_empty() {
+                continue;
+            }
+
+            let (_, name_to_index_map) = self
+                .get_attribute_indices_by_name(
+                    &credential.credential_schema.id,
+                    credential.credential_status.is_some(),
+                )
+                .await?;
+            fail_if_required_statements_hidden(
+                &required_reveal_statements,
+                &name_to_index_map,
+                &credential.credential_subject.data,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Maps attribute names of a schema to the indices of their messages in a credential
+    /// issued for this schema (including hidden messages). As the `credentialStatus` of a
+    /// revocable credential adds messages, indices depend on whether the credential is revocable.
+    async fn get_attribute_indices_by_name(
         &mut self,
         schema_did: &str,
-        revealed_attributes: Option<Vec<String>>,
-    ) -> Result<HashMap<String, Vec<usize>>, PresentationError> {
+        is_revocable: bool,
+    ) -> Result<(CredentialSchema, HashMap<String, usize>), PresentationError> {
         fail_if_not_a_did(schema_did, "schema_did")?;
         let regex = Regex::new(NQUAD_REGEX).map_err(|err| {
             PresentationError::InternalError(format!("regex for nquads invalid; {0}", &err))
         })?;
 
-        // get parsed schema and "clone" it due to move occurring below
         let schema: CredentialSchema = self.get_did_document(schema_did).await?;
         // get nquads for schema
-        let credential_draft = create_draft_credential_from_schema(false, &schema);
+        let credential_draft = create_draft_credential_from_schema(false, is_revocable, &schema);
         let credential_draft_str = serde_json::to_string(&credential_draft).map_err(
             PresentationError::to_serialization_error("UnsignedBbsCredential"),
         )?;
         let nquads = convert_to_nquads(&credential_draft_str).await?;
 
         // avoid duplicated regex applications, so build property to index map beforehand
-        let mut name_to_index_map: HashMap<&str, usize> = HashMap::new();
+        let mut name_to_index_map: HashMap<String, usize> = HashMap::new();
         for (index, nquad) in nquads.iter().enumerate() {
             if let Some(captures) = regex.captures(nquad) {
                 if let Some(name_match) = captures.get(1) {
-                    name_to_index_map.insert(name_match.as_str(), index);
+                    name_to_index_map.insert(
+                        name_match.as_str().to_string(),
+                        index + ADDITIONAL_HIDDEN_MESSAGES_COUNT,
+                    );
                 }
             }
         }
 
+        Ok((schema, name_to_index_map))
+    }
+
+    async fn get_reveal_attributes_indices_map(
+        &mut self,
+        schema_did: &str,
+        revealed_attributes: Option<Vec<String>>,
+        is_revocable: bool,
+    ) -> Result<HashMap<String, Vec<usize>>, PresentationError> {
+        let (schema, name_to_index_map) = self
+            .get_attribute_indices_by_name(schema_did, is_revocable)
+            .await?;
+
         let attribute_names = revealed_attributes
             .unwrap_or_else(|| schema.properties.keys().map(|p| p.to_string()).collect());
 
@@ -502,7 +803,7 @@ impl<'a> Presentation<'a> {
         let mut missing_attributes: Vec<&str> = vec![];
         for attribute_name in attribute_names.iter() {
             if let Some(index) = name_to_index_map.get(attribute_name.as_str()) {
-                attribute_indices.push(*index + ADDITIONAL_HIDDEN_MESSAGES_COUNT);
+                attribute_indices.push(*index);
             } else {
                 missing_attributes.push(attribute_name);
             }
@@ -527,6 +828,7 @@ impl<'a> Presentation<'a> {
 mod tests_proof_request {
 
     use anyhow::Result;
+    use std::collections::HashMap;
     use vade_evan_bbs::{
         BbsProofProposal,
         BbsProofRequest,
@@ -534,9 +836,9 @@ mod tests_proof_request {
         BbsSubProofRequest,
     };
 
-    use crate::{VadeEvan, DEFAULT_SIGNER, DEFAULT_TARGET};
+    use crate::{VadeEvan, VadeEvanError, DEFAULT_SIGNER, DEFAULT_TARGET};
 
-    use super::Presentation;
+    use super::{Presentation, PresentationError, ProofRequestBuilder};
 
     const SIGNER_PRIVATE_KEY: &str =
         "dfcdcb6d5d09411ae9cbe1b0fd9751ba8803dd4b276d5bf9488ae4ede2669106";
@@ -642,6 +944,64 @@ mod tests_proof_request {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn helper_can_build_proof_request() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        for is_revocable in [true, false] {
+            let payload = ProofRequestBuilder::new()
+                .for_schema(SCHEMA_DID)
+                .reveal("zip")
+                .reveal("country")
+                .revocable(is_revocable)
+                .build(&mut vade_evan)
+                .await?;
+            let mut presentation = Presentation::new(&mut vade_evan)?;
+            let result = presentation.create_proof_request_from_proposal(&payload).await?;
+            let (_, name_to_index_map) = presentation
+                .get_attribute_indices_by_name(SCHEMA_DID, is_revocable)
+                .await?;
+
+            let mut parsed: BbsProofRequest = serde_json::from_str(&result)?;
+            assert_eq!(parsed.r#type, "BBS");
+            assert_eq!(parsed.sub_proof_requests[0].schema, SCHEMA_DID);
+            parsed.sub_proof_requests[0].revealed_attributes.sort();
+            let mut expected = vec![name_to_index_map["zip"], name_to_index_map["country"]];
+            expected.sort();
+            assert_eq!(parsed.sub_proof_requests[0].revealed_attributes, expected);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn helper_cannot_build_proof_request_with_unknown_attribute() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+
+        let result = ProofRequestBuilder::new()
+            .for_schema(SCHEMA_DID)
+            .reveal("zip")
+            .reveal("unknown")
+            .build(&mut vade_evan)
+            .await;
+
+        match result {
+            Ok(_) => assert!(false, "got proof request for unknown attribute"),
+            Err(VadeEvanError::PresentationError(
+                PresentationError::InvalidRevealedAttributes(attributes),
+            )) => assert_eq!(attributes, r#""unknown""#),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn helper_cannot_create_proof_request_with_invalid_did() -> Result<()> {
         let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
@@ -835,6 +1195,116 @@ mod tests_proof_request {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn helper_can_verify_presentation_without_revealed_attributes() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut presentation = Presentation::new(&mut vade_evan)?;
+
+        let proof_request_str = presentation
+            .create_proof_request(SCHEMA_DID_2, Some("[]"))
+            .await?;
+        let parsed: BbsProofRequest = serde_json::from_str(&proof_request_str)?;
+        assert!(parsed.sub_proof_requests[0].revealed_attributes.is_empty());
+
+        let presentation_str = presentation
+            .create_presentation(
+                &proof_request_str,
+                CREDENTIAL,
+                MASTER_SECRET,
+                SIGNER_PRIVATE_KEY,
+                SUBJECT_DID,
+                None,
+            )
+            .await?;
+
+        let verify_result = presentation
+            .verify_presentation(&presentation_str, &proof_request_str)
+            .await?;
+        let proof_verification: BbsProofVerification = serde_json::from_str(&verify_result)?;
+
+        assert_eq!(proof_verification.status, "verified".to_string());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn helper_can_verify_presentation_against_request() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut presentation = Presentation::new(&mut vade_evan)?;
+
+        let proof_request_str = presentation
+            .create_proof_request(SCHEMA_DID_2, Some(r#"["test_property_string"]"#))
+            .await?;
+        let presentation_str = presentation
+            .create_presentation(
+                &proof_request_str,
+                CREDENTIAL,
+                MASTER_SECRET,
+                SIGNER_PRIVATE_KEY,
+                SUBJECT_DID,
+                None,
+            )
+            .await?;
+
+        let revealed_claims_str = presentation
+            .verify_presentation_against_request(&presentation_str, &proof_request_str)
+            .await?;
+        let revealed_claims: HashMap<String, HashMap<String, String>> =
+            serde_json::from_str(&revealed_claims_str)?;
+
+        assert_eq!(
+            revealed_claims[SCHEMA_DID_2].get("test_property_string"),
+            Some(&"value".to_string()),
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn helper_rejects_presentation_missing_requested_attribute() -> Result<()> {
+        let mut vade_evan = VadeEvan::new(crate::VadeEvanConfig {
+            target: DEFAULT_TARGET,
+            signer: DEFAULT_SIGNER,
+        })?;
+        let mut presentation = Presentation::new(&mut vade_evan)?;
+
+        let proof_request_str = presentation
+            .create_proof_request(SCHEMA_DID_2, Some(r#"["test_property_string"]"#))
+            .await?;
+        let presentation_str = presentation
+            .create_presentation(
+                &proof_request_str,
+                CREDENTIAL,
+                MASTER_SECRET,
+                SIGNER_PRIVATE_KEY,
+                SUBJECT_DID,
+                None,
+            )
+            .await?;
+        let stricter_proof_request_str = presentation
+            .create_proof_request(SCHEMA_DID_2, Some(r#"["test_property_string2"]"#))
+            .await?;
+
+        let result = presentation
+            .verify_presentation_against_request(&presentation_str, &stricter_proof_request_str)
+            .await;
+
+        match result {
+            Ok(_) => assert!(false, "got unexpected result instead of error"),
+            Err(err) => assert_eq!(
+                err.to_string(),
+                r#"requested attributes have not been revealed: "test_property_string2""#,
+            ),
+        };
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn helper_returns_an_error_if_credential_schema_and_proof_request_schema_mismatch(
     ) -> Result<()> {
@@ -875,5 +1345,39 @@ mod tests_create_presentation {
 
 #[cfg(test)]
 mod tests_verify_presentation {
-    // will be added with further updates
+    use std::collections::HashMap;
+
+    use super::{fail_if_required_statements_hidden, PresentationError};
+
+    fn get_name_to_index_map() -> HashMap<String, usize> {
+        HashMap::from([
+            ("test_property_string".to_string(), 13),
+            ("test_property_string2".to_string(), 14),
+        ])
+    }
+
+    #[test]
+    fn accepts_presentation_revealing_required_statements() {
+        let revealed_data =
+            HashMap::from([("test_property_string".to_string(), "value".to_string())]);
+
+        let result =
+            fail_if_required_statements_hidden(&[1, 13], &get_name_to_index_map(), &revealed_data);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn rejects_presentation_hiding_required_statements() {
+        let revealed_data =
+            HashMap::from([("test_property_string2".to_string(), "value".to_string())]);
+
+        match fail_if_required_statements_hidden(&[13], &get_name_to_index_map(), &revealed_data) {
+            Ok(_) => assert!(false, "hidden required statement should be detected"),
+            Err(PresentationError::RequiredStatementHidden(hidden)) => {
+                assert_eq!(hidden, r#""test_property_string" (13)"#)
+            }
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+    }
 }
@@ -1,3 +1,4 @@
+use serde_json::Value;
 use ssi::{
     jsonld::{json_to_dataset, JsonLdOptions, StaticLoader},
     urdna2015::normalize,
@@ -15,9 +16,79 @@ use vade_evan_bbs::{
 pub enum SharedError {
     #[error("JSON-ld handling failed, {0}")]
     JsonLdHandling(String),
+    #[error("term '{term}' is not defined in document context and would be dropped")]
+    UndefinedTerm { term: String },
 }
 
-pub async fn convert_to_nquads(document_string: &str) -> Result<Vec<String>, SharedError> {
+/// Iterator over the non-empty lines of a normalized nquads document.
+///
+/// Returned by [`convert_to_nquads_iter`], yields one nquad per call to `next` without
+/// collecting all of them into a vector beforehand. The normalized document itself is held in
+/// memory as a whole, as canonicalization requires the complete dataset anyway.
+pub struct NquadLines {
+    normalized: String,
+    offset: usize,
+}
+
+impl Iterator for NquadLines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.offset < self.normalized.len() {
+            let rest = &self.normalized[self.offset..];
+            let (line, consumed) = match rest.find('\n') {
+                Some(index) => (&rest[..index], index + 1),
+                None => (rest, rest.len()),
+            };
+            self.offset += consumed;
+            if !line.is_empty() {
+                return Some(line.to_string());
+            }
+        }
+
+        None
+    }
+}
+
+/// Converts a JSON-LD document to normalized nquads and returns them as an iterator.
+///
+/// Can be used instead of [`convert_to_nquads`] if nquads should be processed one by one,
+/// e.g. when hashing them into signature messages for large credentials. This only avoids
+/// allocating a `String` per nquad up front, the document is still normalized as a whole before
+/// the first nquad is returned.
+///
+/// The document is converted as it is first, so documents signed with their original `@context`
+/// keep their nquads. Only if that fails, e.g. because a context IRI differs from the known one
+/// in case or trailing slash, it is converted again with normalized context IRIs.
+///
+/// # Arguments
+///
+/// * `document_string` - JSON-LD document to convert
+///
+/// # Returns
+/// iterator over normalized nquads
+pub async fn convert_to_nquads_iter(document_string: &str) -> Result<NquadLines, SharedError> {
+    let normalized = match get_normalized_nquads(document_string).await {
+        Ok(normalized) => normalized,
+        Err(err) => {
+            let document: Value = serde_json::from_str(document_string)
+                .map_err(|err| SharedError::JsonLdHandling(err.to_string()))?;
+            let mut normalized_document = document.clone();
+            normalize_context_iris(&mut normalized_document);
+            if normalized_document == document {
+                return Err(err);
+            }
+            get_normalized_nquads(&normalized_document.to_string()).await?
+        }
+    };
+
+    Ok(NquadLines {
+        normalized,
+        offset: 0,
+    })
+}
+
+async fn get_normalized_nquads(document_string: &str) -> Result<String, SharedError> {
     let mut loader = StaticLoader;
     let options = JsonLdOptions {
         base: None,           // -b, Base IRI
@@ -25,7 +96,7 @@ pub async fn convert_to_nquads(document_string: &str) -> Result<Vec<String>, Sha
         ..Default::default()
     };
     let dataset = json_to_dataset(
-        &document_string,
+        document_string,
         None, // will be patched into @context, e.g. Some(&r#"["https://schema.org/"]"#.to_string()),
         false,
         Some(&options),
@@ -33,19 +104,159 @@ pub async fn convert_to_nquads(document_string: &str) -> Result<Vec<String>, Sha
     )
     .await
     .map_err(|err| SharedError::JsonLdHandling(err.to_string()))?;
-    let dataset_normalized = normalize(&dataset).unwrap();
-    let normalized = dataset_normalized.to_nquads().unwrap();
-    let non_empty_lines = normalized
-        .split("\n")
-        .filter(|s| !s.is_empty())
-        .map(|s| s.to_string())
-        .collect();
+    let dataset_normalized =
+        normalize(&dataset).map_err(|err| SharedError::JsonLdHandling(err.to_string()))?;
+
+    dataset_normalized
+        .to_nquads()
+        .map_err(|err| SharedError::JsonLdHandling(err.to_string()))
+}
 
-    Ok(non_empty_lines)
+pub async fn convert_to_nquads(document_string: &str) -> Result<Vec<String>, SharedError> {
+    Ok(convert_to_nquads_iter(document_string).await?.collect())
+}
+
+/// Same as [`convert_to_nquads`], but fails if a property of the document is not defined in its
+/// context. JSON-LD expansion drops those properties, so they would not be part of the signed
+/// messages.
+///
+/// Values of all properties are replaced with unique probe IRIs and the resulting document is
+/// converted once, a property is considered dropped if none of the probes in its value show up
+/// in the nquads. Meant to be used before signing, not for verification.
+pub async fn convert_to_nquads_strict(document_string: &str) -> Result<Vec<String>, SharedError> {
+    let nquads = convert_to_nquads(document_string).await?;
+    let mut probe_document: Value = serde_json::from_str(document_string)
+        .map_err(|err| SharedError::JsonLdHandling(err.to_string()))?;
+
+    let mut probe_count = 0;
+    let mut terms = Vec::new();
+    insert_probes(&mut probe_document, &mut probe_count, &mut terms);
+    let probe_nquads = convert_to_nquads(&probe_document.to_string())
+        .await?
+        .join("\n");
+    // outer properties are collected first, so a dropped object is reported instead of its
+    // nested properties
+    for (term, probes) in terms {
+        if !probes.is_empty()
+            && !probes
+                .iter()
+                .any(|probe| probe_nquads.contains(&get_probe_iri(*probe)))
+        {
+            return Err(SharedError::UndefinedTerm { term });
+        }
+    }
+
+    Ok(nquads)
+}
+
+// normalizes IRIs in all `@context` entries of a document, so equivalent IRIs like
+// "https://schema.org" and "HTTPS://schema.org/" load the same context and produce the same
+// nquads, embedded contexts are kept as they are; only used if the original form cannot be
+// converted
+fn normalize_context_iris(value: &mut Value) {
+    match value {
+        Value::Array(values) => values.iter_mut().for_each(normalize_context_iris),
+        Value::Object(properties) => {
+            for (key, value) in properties.iter_mut() {
+                if key == "@context" {
+                    normalize_context_entry(value);
+                } else {
+                    normalize_context_iris(value);
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+fn normalize_context_entry(context: &mut Value) {
+    match context {
+        Value::String(iri) => *iri = normalize_context_iri(iri),
+        Value::Array(entries) => entries.iter_mut().for_each(normalize_context_entry),
+        _ => (),
+    }
+}
+
+// lowercases scheme and host of an IRI and replaces an empty path with "/", as both are
+// equivalent for http(s) IRIs, paths are kept as they are
+fn normalize_context_iri(iri: &str) -> String {
+    let (scheme, rest) = match iri.split_once("://") {
+        Some(parts) => parts,
+        None => return iri.to_string(),
+    };
+    let (authority, path) = match rest.find(|c| c == '/' || c == '?' || c == '#') {
+        Some(index) => rest.split_at(index),
+        None => (rest, ""),
+    };
+    let separator = if path.starts_with('/') { "" } else { "/" };
+
+    format!(
+        "{}://{}{}{}",
+        scheme.to_lowercase(),
+        authority.to_lowercase(),
+        separator,
+        path
+    )
+}
+
+// replaces all property values and ids in a document with probe IRIs and adds probe ids to nodes
+// without one, so nested objects can be found in the nquads as well, skips keywords and `type`,
+// as types may define scoped contexts, collects all properties with the probes placed in their
+// values and returns the probes placed in `value`
+fn insert_probes(
+    value: &mut Value,
+    probe_count: &mut usize,
+    terms: &mut Vec<(String, Vec<usize>)>,
+) -> Vec<usize> {
+    match value {
+        Value::Array(values) => values
+            .iter_mut()
+            .flat_map(|value| insert_probes(value, probe_count, terms))
+            .collect(),
+        Value::Object(properties) => {
+            let mut probes = Vec::new();
+            let is_node = !["id", "@id", "@value", "@list", "@set"]
+                .iter()
+                .any(|key| properties.contains_key(*key));
+            if is_node {
+                probes.push(*probe_count);
+                properties.insert("@id".to_owned(), Value::from(get_probe_iri(*probe_count)));
+                *probe_count += 1;
+            }
+            for (key, value) in properties.iter_mut() {
+                if key.starts_with('@') || key == "type" {
+                    continue;
+                }
+                if key == "id" {
+                    probes.extend(insert_probes(value, probe_count, terms));
+                    continue;
+                }
+                let term_index = terms.len();
+                terms.push((key.to_owned(), vec![]));
+                let value_probes = insert_probes(value, probe_count, terms);
+                terms[term_index].1 = value_probes.clone();
+                probes.extend(value_probes);
+            }
+            probes
+        }
+        Value::Null => vec![],
+        _ => {
+            let probe = *probe_count;
+            *probe_count += 1;
+            *value = Value::from(get_probe_iri(probe));
+            vec![probe]
+        }
+    }
+}
+
+// terminated with '#', so probe 1 does not match probe 10
+fn get_probe_iri(probe: usize) -> String {
+    format!("urn:vade-evan:probe:{}#", probe)
 }
 
 pub fn create_draft_credential_from_schema(
     use_valid_until: bool,
+    include_credential_status: bool,
     schema: &CredentialSchema,
 ) -> UnsignedBbsCredential {
     let credential = UnsignedBbsCredential {
@@ -76,12 +287,16 @@ pub fn create_draft_credential_from_schema(
             id: schema.id.to_owned(),
             r#type: schema.r#type.to_owned(),
         },
-        credential_status: Some(CredentialStatus {
-            id: "did:evan:zkp:placeholder_status#0".to_string(),
-            r#type: "RevocationList2020Status".to_string(),
-            revocation_list_index: "0".to_string(),
-            revocation_list_credential: "did:evan:zkp:placeholder_status".to_string(),
-        }),
+        credential_status: if include_credential_status {
+            Some(CredentialStatus {
+                id: "did:evan:zkp:placeholder_status#0".to_string(),
+                r#type: "RevocationList2020Status".to_string(),
+                revocation_list_index: "0".to_string(),
+                revocation_list_credential: "did:evan:zkp:placeholder_status".to_string(),
+            })
+        } else {
+            None
+        },
     };
     credential
 }
@@ -110,3 +325,173 @@ pub fn check_for_optional_empty_params(param: Option<&str>) -> Option<&str> {
 pub fn is_did(to_check: &str) -> bool {
     to_check.starts_with("did:")
 }
+
+#[cfg(test)]
+#[cfg(not(all(feature = "c-lib", feature = "target-c-sdk")))]
+mod tests {
+    use anyhow::Result;
+
+    use super::{
+        convert_to_nquads,
+        convert_to_nquads_iter,
+        convert_to_nquads_strict,
+        normalize_context_iri,
+        SharedError,
+    };
+
+    const CREDENTIAL_WITHOUT_PROOF: &str = r###"{
+        "id": "uuid:70b7ec4e-f035-493e-93d3-2cf5be4c7f88",
+        "type": [
+            "VerifiableCredential"
+        ],
+        "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+        "@context": [
+            "https://www.w3.org/2018/credentials/v1",
+            "https://schema.org/",
+            "https://w3id.org/vc-revocation-list-2020/v1"
+        ],
+        "issuanceDate": "2023-02-01T14:08:09.849Z",
+        "credentialSchema": {
+            "id": "did:evan:EiCimsy3uWJ7PivWK0QUYSCkImQnjrx6fGr6nK8XIg26Kg",
+            "type": "EvanVCSchema"
+        },
+        "credentialStatus": {
+            "id": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA#4",
+            "type": "RevocationList2020Status",
+            "revocationListIndex": "4",
+            "revocationListCredential": "did:evan:EiA0Ns-jiPwu2Pl4GQZpkTKBjvFeRXxwGgXRTfG1Lyi8aA"
+        },
+        "credentialSubject": {
+            "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "data": {
+                "bio": "biography"
+            }
+        }
+    }"###;
+
+    #[tokio::test]
+    async fn can_stream_same_nquads_as_collected() -> Result<()> {
+        let collected = convert_to_nquads(CREDENTIAL_WITHOUT_PROOF).await?;
+        let streamed: Vec<String> = convert_to_nquads_iter(CREDENTIAL_WITHOUT_PROOF)
+            .await?
+            .collect();
+
+        assert!(!collected.is_empty());
+        assert_eq!(streamed, collected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalizes_equivalent_context_iris() {
+        for iri in ["https://schema.org", "HTTPS://Schema.org/", "https://schema.org/"] {
+            assert_eq!(normalize_context_iri(iri), "https://schema.org/");
+        }
+        assert_eq!(
+            normalize_context_iri("https://www.w3.org/2018/credentials/v1"),
+            "https://www.w3.org/2018/credentials/v1",
+        );
+        assert_eq!(normalize_context_iri("urn:example:context"), "urn:example:context");
+    }
+
+    #[tokio::test]
+    async fn converts_equivalent_context_iris_to_same_nquads() -> Result<()> {
+        let expected = convert_to_nquads(CREDENTIAL_WITHOUT_PROOF).await?;
+        for schema_context in ["https://schema.org", "HTTPS://schema.org/"] {
+            let mut document: serde_json::Value = serde_json::from_str(CREDENTIAL_WITHOUT_PROOF)?;
+            document["@context"][1] = serde_json::Value::from(schema_context);
+
+            assert_eq!(convert_to_nquads(&document.to_string()).await?, expected);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn can_convert_document_with_defined_terms_in_strict_mode() -> Result<()> {
+        let strict = convert_to_nquads_strict(CREDENTIAL_WITHOUT_PROOF).await?;
+
+        assert_eq!(strict, convert_to_nquads(CREDENTIAL_WITHOUT_PROOF).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_undefined_terms_in_strict_mode() -> Result<()> {
+        let document = r###"{
+            "@context": ["https://www.w3.org/2018/credentials/v1"],
+            "type": ["VerifiableCredential"],
+            "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "issuanceDate": "2023-02-01T14:08:09.849Z",
+            "credentialSubject": {
+                "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+                "undefinedProperty": "value"
+            }
+        }"###;
+
+        // silently dropped in default mode
+        assert!(convert_to_nquads(document).await.is_ok());
+        match convert_to_nquads_strict(document).await {
+            Ok(_) => assert!(false, "got no error when converting undefined terms"),
+            Err(SharedError::UndefinedTerm { term }) => assert_eq!(term, "undefinedProperty"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn compares_terms_with_expanded_document_in_strict_mode() -> Result<()> {
+        let mut document = serde_json::json!({
+            "@context": [
+                "https://www.w3.org/2018/credentials/v1",
+                { "fullName": "http://example.org/vocab#name" }
+            ],
+            "type": ["VerifiableCredential"],
+            "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "issuanceDate": "2023-02-01T14:08:09.849Z",
+            "credentialSubject": {
+                "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+                "fullName": "Jane Doe"
+            }
+        });
+
+        // term expands to an IRI not ending with its name
+        convert_to_nquads_strict(&document.to_string()).await?;
+
+        // undefined term ending like the IRI of a defined one
+        document["credentialSubject"]["name"] = serde_json::Value::from("Jane Doe");
+        match convert_to_nquads_strict(&document.to_string()).await {
+            Ok(_) => assert!(false, "got no error when converting undefined terms"),
+            Err(SharedError::UndefinedTerm { term }) => assert_eq!(term, "name"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_undefined_terms_in_nested_objects_in_strict_mode() -> Result<()> {
+        let document = serde_json::json!({
+            "@context": [
+                "https://www.w3.org/2018/credentials/v1",
+                { "data": "http://example.org/vocab#data" }
+            ],
+            "type": ["VerifiableCredential"],
+            "issuer": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+            "issuanceDate": "2023-02-01T14:08:09.849Z",
+            "credentialSubject": {
+                "id": "did:evan:EiAee4ixDnSP0eWyp0YFV7Wt9yrZ3w841FNuv9NSLFSCVA",
+                "data": { "bio": "biography" }
+            }
+        });
+
+        match convert_to_nquads_strict(&document.to_string()).await {
+            Ok(_) => assert!(false, "got no error when converting undefined terms"),
+            Err(SharedError::UndefinedTerm { term }) => assert_eq!(term, "bio"),
+            Err(err) => assert!(false, "got unexpected error: {}", err),
+        };
+
+        Ok(())
+    }
+}
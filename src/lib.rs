@@ -30,4 +30,29 @@ mod wasm_lib;
 mod api;
 mod helpers;
 
-pub use crate::api::{VadeEvan, VadeEvanConfig, VadeEvanError, DEFAULT_SIGNER, DEFAULT_TARGET};
+pub use crate::api::{
+    BatchOperation,
+    VadeEvan,
+    VadeEvanConfig,
+    VadeEvanError,
+    DEFAULT_SIGNER,
+    DEFAULT_TARGET,
+};
+pub use crate::helpers::{
+    DidDocumentBuilder,
+    DidService,
+    IdentityDidDocument,
+    PublicKeyJwk,
+    ValidationError,
+    VerificationMethod,
+    VerificationRelationship,
+};
+#[cfg(feature = "did-sidetree")]
+pub use crate::helpers::normalize_evan_did;
+#[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+pub use crate::helpers::{
+    AttributeMapping,
+    CredentialOfferOptions,
+    ProofRequestBuilder,
+    RevocationState,
+};
@@ -235,13 +235,21 @@ async fn main() -> Result<()> {
                         Some(value) => value.to_lowercase() == "true",
                         None => false,
                     };
+                let validate_issuer = match get_optional_argument_value(sub_m, "validate_issuer") {
+                    Some(value) => value.to_lowercase() == "true",
+                    None => false,
+                };
                 get_vade_evan(sub_m)?
-                    .helper_create_credential_offer(
+                    .helper_create_credential_offer_with_options(
                         get_argument_value(sub_m, "schema_did", None),
                         use_valid_until,
                         get_argument_value(sub_m, "issuer_did", None),
                         include_credential_status,
                         get_argument_value(sub_m, "required_reveal_statements", None),
+                        &vade_evan::CredentialOfferOptions {
+                            validate_issuer,
+                            ..Default::default()
+                        },
                     )
                     .await?
             }
@@ -283,9 +291,10 @@ async fn main() -> Result<()> {
             #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
             ("verify_credential", Some(sub_m)) => {
                 get_vade_evan(sub_m)?
-                    .helper_verify_credential(
+                    .helper_verify_credential_with_subject(
                         get_argument_value(sub_m, "credential", None),
                         get_argument_value(sub_m, "master_secret", None),
+                        get_optional_argument_value(sub_m, "expected_subject"),
                     )
                     .await?;
                 "".to_string()
@@ -402,6 +411,7 @@ fn add_subcommand_helper<'a>(app: App<'a, 'a>) -> Result<App<'a, 'a>> {
                     .arg(get_clap_argument("issuer_did")?)
                     .arg(get_clap_argument("include_credential_status")?)
                     .arg(get_clap_argument("required_reveal_statements")?)
+                    .arg(get_clap_argument("validate_issuer")?)
             );
         } else {}
     }
@@ -460,6 +470,7 @@ fn add_subcommand_helper<'a>(app: App<'a, 'a>) -> Result<App<'a, 'a>> {
                     .about("Verifies a given credential by checking if given master secret was incorporated into proof and if proof was signed with issuers public key.")
                     .arg(get_clap_argument("credential")?)
                     .arg(get_clap_argument("master_secret")?)
+                    .arg(get_clap_argument("expected_subject")?)
             );
         } else {}
     }
@@ -978,6 +989,11 @@ fn get_clap_argument(arg_name: &str) -> Result<Arg> {
             .value_name("schema_did")
             .help("schema to create the offer for, e.g. 'did:evan:EiACv4q04NPkNRXQzQHOEMa3r1p_uINgX75VYP2gaK5ADw'")
             .takes_value(true),
+        "validate_issuer" => Arg::with_name("validate_issuer")
+            .long("validate_issuer")
+            .value_name("validate_issuer")
+            .help("true if issuer DID should be resolved and checked for a signing key")
+            .takes_value(true),
         "use_valid_until" => Arg::with_name("use_valid_until")
             .long("use_valid_until")
             .value_name("use_valid_until")
@@ -1071,6 +1087,12 @@ fn get_clap_argument(arg_name: &str) -> Result<Arg> {
             .value_name("credential_revocation_id")
             .help("index in revocation list")
             .takes_value(true),
+        "expected_subject" => Arg::with_name("expected_subject")
+            .long("expected_subject")
+            .value_name("expected_subject")
+            .required(false)
+            .help("DID the `credentialSubject.id` of the credential has to match")
+            .takes_value(true),
         "exp_date" => Arg::with_name("exp_date")
             .long("exp_date")
             .value_name("exp_date")
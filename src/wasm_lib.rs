@@ -110,6 +110,8 @@ struct HelperCreateCredentialOfferPayload {
     pub subject_did: Option<String>,
     pub is_credential_status_included: bool,
     pub required_reveal_statements: String,
+    #[serde(default)]
+    pub validate_issuer: bool,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -135,6 +137,15 @@ struct HelperRevokeCredentialPayload {
 struct HelperVerifyCredentialPayload {
     pub credential: String,
     pub master_secret: String,
+    pub expected_subject: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HelperVerifyCredentialAnyKeyPayload {
+    pub credential: String,
+    pub candidate_method_ids: String,
+    pub master_secret: String,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -337,15 +348,39 @@ cfg_if::cfg_if! {
             issuer_did: String,
             is_credential_status_included: bool,
             required_reveal_statements: String,
+        ) -> Result<String, JsValue> {
+            helper_create_credential_offer_with_options(
+                schema_did,
+                use_valid_until,
+                issuer_did,
+                is_credential_status_included,
+                required_reveal_statements,
+                false,
+            ).await
+        }
+
+        #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+        #[wasm_bindgen]
+        pub async fn helper_create_credential_offer_with_options(
+            schema_did: String,
+            use_valid_until: bool,
+            issuer_did: String,
+            is_credential_status_included: bool,
+            required_reveal_statements: String,
+            validate_issuer: bool,
         ) -> Result<String, JsValue> {
             let mut vade_evan = get_vade_evan(None).map_err(jsify_generic_error)?;
             let offer = vade_evan
-                .helper_create_credential_offer(
+                .helper_create_credential_offer_with_options(
                     &schema_did,
                     use_valid_until,
                     &issuer_did,
                     is_credential_status_included,
                     &required_reveal_statements,
+                    &crate::helpers::CredentialOfferOptions {
+                        validate_issuer,
+                        ..Default::default()
+                    },
                 ).await
                 .map_err(jsify_vade_evan_error)?;
             Ok(offer)
@@ -394,17 +429,36 @@ cfg_if::cfg_if! {
         pub async fn helper_verify_credential(
             credential: String,
             master_secret: String,
+            expected_subject: Option<String>,
         ) -> Result<String, JsValue> {
             let mut vade_evan = get_vade_evan(None).map_err(jsify_generic_error)?;
             vade_evan
-                .helper_verify_credential(
+                .helper_verify_credential_with_subject(
                     &credential,
                     &master_secret,
+                    expected_subject.as_deref(),
                 ).await
                 .map_err(jsify_vade_evan_error)?;
             Ok("".to_string())
         }
 
+        #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+        #[wasm_bindgen]
+        pub async fn helper_verify_credential_any_key(
+            credential: String,
+            candidate_method_ids: String,
+            master_secret: String,
+        ) -> Result<String, JsValue> {
+            let mut vade_evan = get_vade_evan(None).map_err(jsify_generic_error)?;
+            Ok(vade_evan
+                .helper_verify_credential_any_key(
+                    &credential,
+                    &candidate_method_ids,
+                    &master_secret,
+                ).await
+                .map_err(jsify_vade_evan_error)?)
+        }
+
         #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
         #[wasm_bindgen]
         pub async fn helper_create_self_issued_credential(
@@ -712,12 +766,13 @@ pub async fn execute_vade(
             let payload_result = parse::<HelperCreateCredentialOfferPayload>(&payload);
             match payload_result {
                 Ok(payload) => {
-                    helper_create_credential_offer(
+                    helper_create_credential_offer_with_options(
                         payload.schema_did,
                         payload.use_valid_until,
                         payload.issuer_did,
                         payload.is_credential_status_included,
                         payload.required_reveal_statements,
+                        payload.validate_issuer,
                     )
                     .await
                 }
@@ -761,7 +816,27 @@ pub async fn execute_vade(
             let payload_result = parse::<HelperVerifyCredentialPayload>(&payload);
             match payload_result {
                 Ok(payload) => {
-                    helper_verify_credential(payload.credential, payload.master_secret).await
+                    helper_verify_credential(
+                        payload.credential,
+                        payload.master_secret,
+                        payload.expected_subject,
+                    )
+                    .await
+                }
+                Err(error) => Err(get_parsing_error_message(&error, &payload)),
+            }
+        }
+        #[cfg(all(feature = "vc-zkp-bbs", feature = "did-sidetree"))]
+        "helper_verify_credential_any_key" => {
+            let payload_result = parse::<HelperVerifyCredentialAnyKeyPayload>(&payload);
+            match payload_result {
+                Ok(payload) => {
+                    helper_verify_credential_any_key(
+                        payload.credential,
+                        payload.candidate_method_ids,
+                        payload.master_secret,
+                    )
+                    .await
                 }
                 Err(error) => Err(get_parsing_error_message(&error, &payload)),
             }